@@ -0,0 +1,65 @@
+//! Compares the divide-and-conquer radix conversion with the schoolbook
+//! path on decimal inputs of growing size. Run with `cargo bench`.
+
+use std::time::{Duration, Instant};
+
+use base_converter::bigint::BigUint;
+
+/// The schoolbook path is skipped above this many digits, where it takes
+/// minutes.
+const SCHOOLBOOK_LIMIT: usize = 200_000;
+
+fn random_decimal(count: usize) -> Vec<u32> {
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    let mut digits: Vec<u32> = (0..count)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            (state % 10) as u32
+        })
+        .collect();
+    digits[0] = digits[0].max(1);
+    digits
+}
+
+fn time<T>(run: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let result = run();
+    (result, start.elapsed())
+}
+
+fn main() {
+    println!(
+        "{:>10}  {:>14}  {:>14}  {:>14}  {:>14}",
+        "digits", "parse", "parse (naive)", "to hex", "to hex (naive)"
+    );
+    for count in [1_000, 3_000, 10_000, 100_000, 1_000_000, 4_000_000] {
+        let digits = random_decimal(count);
+        let (value, parse) = time(|| BigUint::from_digits(&digits, 10));
+        let (hex, to_hex) = time(|| value.to_digits(16));
+
+        let naive = if count <= SCHOOLBOOK_LIMIT {
+            let (naive_value, naive_parse) = time(|| BigUint::from_digits_schoolbook(&digits, 10));
+            let (naive_hex, naive_to_hex) = time(|| value.to_digits_schoolbook(16));
+            assert_eq!(naive_value, value);
+            assert_eq!(naive_hex, hex);
+            (
+                format!("{:.3?}", naive_parse),
+                format!("{:.3?}", naive_to_hex),
+            )
+        } else {
+            ("skipped".to_string(), "skipped".to_string())
+        };
+        assert_eq!(value.to_digits(10), digits);
+
+        println!(
+            "{:>10}  {:>14}  {:>14}  {:>14}  {:>14}",
+            count,
+            format!("{:.3?}", parse),
+            naive.0,
+            format!("{:.3?}", to_hex),
+            naive.1
+        );
+    }
+}
@@ -0,0 +1,136 @@
+const STANDARD: &str = "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+const BASE58: &str = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+const BASE62: &str = "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+const BASE64: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// The ordered digit symbols of a base: symbol `i` stands for digit value `i`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Alphabet {
+    symbols: Vec<char>,
+    case_insensitive: bool,
+}
+
+impl Alphabet {
+    /// Builds a case-sensitive alphabet from a string of distinct symbols.
+    pub fn new(symbols: &str) -> Result<Self, String> {
+        let symbols: Vec<char> = symbols.chars().collect();
+        if symbols.len() < 2 {
+            return Err("An alphabet needs at least 2 symbols".to_string());
+        }
+
+        for (i, &c) in symbols.iter().enumerate() {
+            if c == '.' || c == '(' || c == ')' || c.is_whitespace() {
+                return Err(format!("'{}' cannot be used as a digit symbol", c));
+            }
+            if symbols[..i].contains(&c) {
+                return Err(format!("Duplicate symbol '{}' in alphabet", c));
+            }
+        }
+
+        Ok(Alphabet {
+            symbols,
+            case_insensitive: false,
+        })
+    }
+
+    /// `0-9A-Z`, accepting lowercase letters on input.
+    pub fn standard() -> Self {
+        Alphabet {
+            symbols: STANDARD.chars().collect(),
+            case_insensitive: true,
+        }
+    }
+
+    /// Bitcoin base58, which drops the look-alike symbols `0`, `O`, `I` and `l`.
+    pub fn base58() -> Self {
+        Alphabet::new(BASE58).unwrap()
+    }
+
+    pub fn base62() -> Self {
+        Alphabet::new(BASE62).unwrap()
+    }
+
+    /// The RFC 4648 base64 digit order.
+    pub fn base64() -> Self {
+        Alphabet::new(BASE64).unwrap()
+    }
+
+    /// Matches input symbols exactly, so `a` and `A` can be different digits.
+    pub fn case_sensitive(mut self) -> Self {
+        self.case_insensitive = false;
+        self
+    }
+
+    /// Folds case on input. Fails when two symbols differ only in case, since
+    /// folding would make them ambiguous.
+    pub fn case_insensitive(mut self) -> Result<Self, String> {
+        for (i, &c) in self.symbols.iter().enumerate() {
+            if let Some(&other) = self.symbols[..i].iter().find(|&&s| same_letter(s, c)) {
+                return Err(format!(
+                    "Symbols '{}' and '{}' differ only in case, so the alphabet must be case-sensitive",
+                    other, c
+                ));
+            }
+        }
+        self.case_insensitive = true;
+        Ok(self)
+    }
+
+    pub fn is_case_sensitive(&self) -> bool {
+        !self.case_insensitive
+    }
+
+    /// Resolves a preset name, or otherwise treats `spec` as literal symbols.
+    pub fn from_spec(spec: &str) -> Result<Self, String> {
+        match spec.to_lowercase().as_str() {
+            "standard" | "base36" => Ok(Alphabet::standard()),
+            "base58" | "bitcoin" => Ok(Alphabet::base58()),
+            "base62" => Ok(Alphabet::base62()),
+            "base64" => Ok(Alphabet::base64()),
+            _ => Alphabet::new(spec),
+        }
+    }
+
+    /// The largest base this alphabet can write.
+    pub fn max_base(&self) -> u32 {
+        self.symbols.len() as u32
+    }
+
+    pub fn digit_value(&self, c: char) -> Option<u32> {
+        let position = self.symbols.iter().position(|&symbol| symbol == c);
+        let position = match position {
+            None if self.case_insensitive => self
+                .symbols
+                .iter()
+                .position(|&symbol| same_letter(symbol, c)),
+            found => found,
+        };
+        position.map(|p| p as u32)
+    }
+
+    /// For a case-sensitive alphabet, the symbol `c` would match if its case
+    /// were ignored.
+    pub fn case_mismatch(&self, c: char) -> Option<char> {
+        if self.case_insensitive || self.symbols.contains(&c) {
+            return None;
+        }
+        self.symbols
+            .iter()
+            .copied()
+            .find(|&symbol| same_letter(symbol, c))
+    }
+
+    pub fn symbol(&self, digit: u32) -> char {
+        self.symbols[digit as usize]
+    }
+}
+
+fn same_letter(a: char, b: char) -> bool {
+    a != b && a.to_lowercase().eq(b.to_lowercase())
+}
+
+impl Default for Alphabet {
+    fn default() -> Self {
+        Alphabet::standard()
+    }
+}
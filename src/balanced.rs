@@ -0,0 +1,226 @@
+use crate::alphabet::Alphabet;
+use crate::bigint::BigUint;
+use crate::converter::{RoundingMode, place_point, rational_value, round_scaled};
+use crate::formatter::{FormatOptions, format_result_with};
+use crate::number::Number;
+use crate::parser::{parse_digits, split_sign, strip_separators};
+
+/// A number in a balanced base `b`, whose digits run from `-(b-1)/2` to
+/// `(b-1)/2`, so the sign lives in the digits themselves.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SignedDigits {
+    pub integer: Vec<i32>,
+    pub fractional: Vec<i32>,
+}
+
+impl SignedDigits {
+    pub fn new(integer: Vec<i32>, fractional: Vec<i32>) -> Self {
+        SignedDigits {
+            integer,
+            fractional,
+        }
+    }
+
+    /// Flips every digit, which negates the value.
+    pub fn negated(mut self) -> Self {
+        for digit in self.integer.iter_mut().chain(&mut self.fractional) {
+            *digit = -*digit;
+        }
+        self
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.integer
+            .iter()
+            .chain(&self.fractional)
+            .all(|&digit| digit == 0)
+    }
+}
+
+fn check_odd(base: u32) -> Result<(), String> {
+    if base < 3 || base.is_multiple_of(2) {
+        return Err(format!(
+            "Balanced bases must be odd and at least 3, got {}",
+            base
+        ));
+    }
+    Ok(())
+}
+
+/// Checks that `base` is odd, at least 3 and within the symbols of `alphabet`.
+pub fn check_balanced_base(base: u32, alphabet: &Alphabet) -> Result<(), String> {
+    check_odd(base)?;
+    if base > alphabet.max_base() {
+        return Err(format!(
+            "Balanced base {} needs {} digit symbols, but the alphabet has {}",
+            base,
+            base,
+            alphabet.max_base()
+        ));
+    }
+    Ok(())
+}
+
+/// The default symbols of a balanced base, ordered from the most negative
+/// digit up, so symbol `i` stands for digit `i - (b-1)/2`. Balanced ternary
+/// is the conventional `T01`; larger bases write `-1, -2, ...` as `Z, Y, ...`
+/// and non-negative digits as usual, up to base 27.
+pub fn balanced_alphabet(base: u32) -> Result<Alphabet, String> {
+    check_odd(base)?;
+    if base == 3 {
+        return Alphabet::new("T01")?.case_insensitive();
+    }
+    if base > 27 {
+        return Err(format!(
+            "Balanced base {} has no default symbols; give an --alphabet of {} symbols",
+            base, base
+        ));
+    }
+
+    let half = base / 2;
+    let negative = (1..=half).rev().map(|d| (b'Z' + 1 - d as u8) as char);
+    let positive = Alphabet::standard();
+    let positive = (0..=half).map(|d| positive.symbol(d));
+    let symbols: String = negative.chain(positive).collect();
+    Alphabet::new(&symbols)?.case_insensitive()
+}
+
+/// Reads a balanced number written with `alphabet`, where symbol `i` is digit
+/// `i - (b-1)/2`. A leading `-` flips every digit.
+pub fn parse_balanced(value: &str, base: u32, alphabet: &Alphabet) -> Result<SignedDigits, String> {
+    check_balanced_base(base, alphabet)?;
+    let value = strip_separators(value, alphabet)?;
+    let (sign, value) = split_sign(&value, alphabet);
+
+    let (integer, fractional) = value.split_once('.').unwrap_or((value, ""));
+    if fractional.contains('.') {
+        return Err("Multiple radix points found".to_string());
+    }
+    if integer.is_empty() && fractional.is_empty() {
+        return Err("Empty number".to_string());
+    }
+
+    let offset = (base / 2) as i32;
+    let signed = |text: &str| -> Result<Vec<i32>, String> {
+        Ok(parse_digits(text, base, alphabet)?
+            .into_iter()
+            .map(|index| index as i32 - offset)
+            .collect())
+    };
+    let integer = if integer.is_empty() {
+        vec![0]
+    } else {
+        signed(integer)?
+    };
+    let number = SignedDigits::new(integer, signed(fractional)?);
+
+    Ok(if sign == "-" {
+        number.negated()
+    } else {
+        number
+    })
+}
+
+/// Writes a balanced number with the symbols of `options.alphabet`, ordered
+/// from the most negative digit up.
+pub fn format_balanced(digits: &SignedDigits, base: u32, options: &FormatOptions) -> String {
+    let offset = (base / 2) as i32;
+    let indices = |digits: &[i32]| -> Vec<u32> {
+        digits
+            .iter()
+            .map(|&digit| (digit + offset) as u32)
+            .collect()
+    };
+    let integer = if digits.integer.is_empty() {
+        vec![offset as u32]
+    } else {
+        indices(&digits.integer)
+    };
+    format_result_with(&integer, &indices(&digits.fractional), options)
+}
+
+/// The exact value of a balanced number as an ordinary number in the same
+/// base, with the same count of fraction digits.
+pub fn from_balanced(digits: &SignedDigits, base: u32) -> Number {
+    let written = [digits.integer.as_slice(), &digits.fractional].concat();
+    let part = |keep: fn(i32) -> bool| -> Vec<u32> {
+        written
+            .iter()
+            .map(|&digit| if keep(digit) { digit.unsigned_abs() } else { 0 })
+            .collect()
+    };
+    let positive = BigUint::from_digits(&part(|d| d > 0), base);
+    let negative = BigUint::from_digits(&part(|d| d < 0), base);
+
+    let (is_negative, magnitude) = if positive >= negative {
+        (false, &positive - &negative)
+    } else {
+        (true, &negative - &positive)
+    };
+
+    let places = digits.fractional.len();
+    let mut scaled = magnitude.to_digits(base);
+    if scaled.len() <= places {
+        scaled.splice(0..0, vec![0; places + 1 - scaled.len()]);
+    }
+    let fractional = scaled.split_off(scaled.len() - places);
+    Number {
+        negative: is_negative,
+        ..Number::new(scaled, fractional)
+    }
+}
+
+/// Writes a signed integer in balanced base `base`, most significant digit
+/// first.
+fn balanced_integer(negative: bool, mut magnitude: BigUint, base: u32) -> Vec<i32> {
+    let half = base / 2;
+    let mut digits = Vec::new();
+    while !magnitude.is_zero() {
+        let remainder = magnitude.divrem_small(base);
+        let digit = if remainder > half {
+            // m = qb + r = (q + 1)b + (r - b)
+            magnitude.add_small(1);
+            remainder as i32 - base as i32
+        } else {
+            remainder as i32
+        };
+        digits.push(if negative { -digit } else { digit });
+    }
+
+    if digits.is_empty() {
+        digits.push(0);
+    }
+    digits.reverse();
+    digits
+}
+
+/// Converts `number`, read in the ordinary base `from_base`, to the odd
+/// balanced base `base` with at most `precision` fraction digits. The
+/// magnitude scaled by `base^precision` is rounded with `rounding`, so values
+/// that terminate within that many digits come out exactly.
+pub fn to_balanced(
+    number: &Number,
+    from_base: u32,
+    base: u32,
+    precision: usize,
+    rounding: RoundingMode,
+) -> SignedDigits {
+    let (numerator, denominator) = rational_value(number, from_base);
+    // Ties go to an even balanced digit, which is r or r - b for the
+    // ordinary last digit r
+    let magnitude = round_scaled(
+        &numerator,
+        &denominator,
+        base,
+        precision,
+        rounding,
+        |m| match m.clone().divrem_small(base) {
+            r if r > base / 2 => base - r,
+            r => r,
+        },
+    );
+
+    let digits = balanced_integer(number.negative, magnitude, base);
+    let (integer, fractional) = place_point(digits, precision);
+    SignedDigits::new(integer, fractional)
+}
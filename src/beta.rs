@@ -0,0 +1,502 @@
+use crate::alphabet::Alphabet;
+use crate::bigint::BigUint;
+use crate::converter::{
+    Expansion, RoundingMode, convert_rational, expand_rational, rational_value,
+};
+use crate::negabase::{Signed, add_signed, mul_signed};
+use crate::number::Number;
+use crate::parser::parse_number_with;
+
+/// Decimal digits kept when an irrational radix is approximated.
+pub const APPROXIMATION_DIGITS: usize = 50;
+
+const PI: &str = "3.14159265358979323846264338327950288419716939937510";
+const E: &str = "2.71828182845904523536028747135266249775724709369995";
+
+/// A radix greater than 1 that need not be an integer, held as the fraction
+/// `numerator / denominator`. Irrational radices such as e, π or √2 are
+/// rational approximations and are not `exact`; square roots and φ also keep
+/// their `quadratic` form, which expansions use instead.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RealRadix {
+    pub name: String,
+    pub numerator: BigUint,
+    pub denominator: BigUint,
+    pub exact: bool,
+    pub quadratic: Option<QuadraticRadix>,
+}
+
+/// The quadratic irrational `(p + q√n) / r`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QuadraticRadix {
+    pub p: u32,
+    pub q: u32,
+    pub r: u32,
+    pub n: u32,
+}
+
+/// The number `(a + b√n) / c`, with `c` positive.
+#[derive(Clone)]
+struct Surd {
+    a: Signed,
+    b: Signed,
+    c: BigUint,
+}
+
+fn signed(value: u64) -> Signed {
+    (false, BigUint::from_u64(value))
+}
+
+fn negate((negative, magnitude): Signed) -> Signed {
+    (!negative && !magnitude.is_zero(), magnitude)
+}
+
+impl QuadraticRadix {
+    /// `x β`.
+    fn times(&self, x: Surd) -> Surd {
+        let (p, q, n) = (
+            signed(self.p as u64),
+            signed(self.q as u64),
+            signed(self.n as u64),
+        );
+        let bqn = mul_signed(mul_signed(x.b.clone(), q.clone()), n);
+        let aq = mul_signed(x.a.clone(), q);
+        Surd {
+            a: add_signed(mul_signed(x.a, p.clone()), bqn),
+            b: add_signed(aq, mul_signed(x.b, p)),
+            c: &x.c * &BigUint::from_u64(self.r as u64),
+        }
+    }
+
+    /// `x / β = x r (p - q√n) / (p² - q²n)`.
+    fn divide(&self, x: Surd) -> Surd {
+        let (p, q, n) = (
+            signed(self.p as u64),
+            signed(self.q as u64),
+            signed(self.n as u64),
+        );
+        let r = signed(self.r as u64);
+        let bqn = mul_signed(mul_signed(x.b.clone(), q.clone()), n.clone());
+        let aq = mul_signed(x.a.clone(), q.clone());
+        let a = add_signed(mul_signed(x.a, p.clone()), negate(bqn));
+        let b = add_signed(mul_signed(x.b, p.clone()), negate(aq));
+        let norm = add_signed(
+            mul_signed(p.clone(), p),
+            negate(mul_signed(mul_signed(q.clone(), q), n)),
+        );
+        let (a, b) = (mul_signed(a, r.clone()), mul_signed(b, r));
+        let (a, b) = if norm.0 {
+            (negate(a), negate(b))
+        } else {
+            (a, b)
+        };
+        Surd {
+            a,
+            b,
+            c: &x.c * &norm.1,
+        }
+    }
+
+    /// `⌊x⌋` for `x >= 0`.
+    fn floor(&self, x: &Surd) -> BigUint {
+        // b√n lies between ±⌊√(b²n)⌋ and the next integer out
+        let root = isqrt(&(&(&x.b.1 * &x.b.1) * &BigUint::from_u64(self.n as u64)));
+        let numerator = if x.b.1.is_zero() {
+            x.a.clone()
+        } else if x.b.0 {
+            add_signed(x.a.clone(), (true, &root + &BigUint::one()))
+        } else {
+            add_signed(x.a.clone(), (false, root))
+        };
+        numerator.1.divrem(&x.c).0
+    }
+
+    /// The greedy digits of `value / denominator` from place `K` down to
+    /// `-precision`, with `K`, and whether anything was left over.
+    fn expand(
+        &self,
+        value: &BigUint,
+        denominator: &BigUint,
+        precision: usize,
+    ) -> (Vec<u32>, usize, bool) {
+        let mut x = Surd {
+            a: (false, value.clone()),
+            b: (false, BigUint::zero()),
+            c: denominator.clone(),
+        };
+        // Divide x down to below β so that its floor is the digit at place K
+        let mut top = 0;
+        loop {
+            let next = self.divide(x.clone());
+            if self.floor(&next).is_zero() {
+                break;
+            }
+            x = next;
+            top += 1;
+        }
+
+        let mut digits = Vec::with_capacity(top + precision + 1);
+        for place in 0..=top + precision {
+            if place > 0 {
+                x = self.times(x);
+            }
+            let digit = self.floor(&x);
+            x.a = add_signed(x.a, (true, &digit * &x.c));
+            digits.push(digit.to_u128().unwrap() as u32);
+        }
+        (digits, top, !(x.a.1.is_zero() && x.b.1.is_zero()))
+    }
+}
+
+fn gcd(a: &BigUint, b: &BigUint) -> BigUint {
+    let (mut a, mut b) = (a.clone(), b.clone());
+    while !b.is_zero() {
+        let remainder = a.divrem(&b).1;
+        a = b;
+        b = remainder;
+    }
+    a
+}
+
+fn power(base: &BigUint, exponent: usize) -> BigUint {
+    let mut result = BigUint::one();
+    for _ in 0..exponent {
+        result = &result * base;
+    }
+    result
+}
+
+/// The integer square root, rounded down.
+fn isqrt(value: &BigUint) -> BigUint {
+    if value.is_zero() {
+        return BigUint::zero();
+    }
+    let mut root = BigUint::pow(2, value.bits().div_ceil(2) as usize);
+    loop {
+        let mut next = &root + &value.divrem(&root).0;
+        next.divrem_small(2);
+        if next >= root {
+            return root;
+        }
+        root = next;
+    }
+}
+
+/// Reads a decimal such as `2.718` as an exact fraction.
+fn decimal_fraction(text: &str) -> Option<(BigUint, BigUint)> {
+    let (integer, fraction) = text.split_once('.').unwrap_or((text, ""));
+    if integer.is_empty() && fraction.is_empty() {
+        return None;
+    }
+    let digits = format!("{}{}", integer, fraction)
+        .chars()
+        .map(|c| c.to_digit(10))
+        .collect::<Option<Vec<u32>>>()?;
+    Some((
+        BigUint::from_digits(&digits, 10),
+        BigUint::pow(10, fraction.len()),
+    ))
+}
+
+impl RealRadix {
+    /// Builds an exact radix from a fraction in lowest terms.
+    fn rational(name: String, numerator: BigUint, denominator: BigUint) -> Result<Self, String> {
+        if denominator.is_zero() {
+            return Err(format!("Radix '{}' divides by zero", name));
+        }
+        if numerator <= denominator {
+            return Err(format!("Radix {} must be greater than 1", name));
+        }
+        let common = gcd(&numerator, &denominator);
+        Ok(RealRadix {
+            name,
+            numerator: numerator.divrem(&common).0,
+            denominator: denominator.divrem(&common).0,
+            exact: true,
+            quadratic: None,
+        })
+    }
+
+    fn approximation(
+        name: &str,
+        numerator: BigUint,
+        denominator: BigUint,
+        quadratic: Option<QuadraticRadix>,
+    ) -> Self {
+        RealRadix {
+            name: name.to_string(),
+            numerator,
+            denominator,
+            exact: false,
+            quadratic,
+        }
+    }
+
+    /// Whether expansions in this radix work from a rounded value of it, as
+    /// they do for e and π.
+    pub fn approximated(&self) -> bool {
+        !self.exact && self.quadratic.is_none()
+    }
+
+    /// `√n`, exact for perfect squares and approximated otherwise.
+    fn square_root(n: u32) -> Result<Self, String> {
+        let root = (n as f64).sqrt().round() as u32;
+        if root as u64 * root as u64 == n as u64 {
+            return RealRadix::rational(
+                format!("√{}", n),
+                BigUint::from_u64(root as u64),
+                BigUint::one(),
+            );
+        }
+        let scale = BigUint::pow(10, APPROXIMATION_DIGITS);
+        let scaled = &BigUint::from_u64(n as u64) * &(&scale * &scale);
+        Ok(RealRadix::approximation(
+            &format!("√{}", n),
+            isqrt(&scaled),
+            scale,
+            Some(QuadraticRadix {
+                p: 0,
+                q: 1,
+                r: 1,
+                n,
+            }),
+        ))
+    }
+
+    /// Reads `3/2`, a decimal such as `1.5`, `sqrt2` (or `sqrt(2)`, `√2`),
+    /// or one of the constants `e`, `pi` (`π`) and `phi` (`φ`).
+    pub fn parse(spec: &str) -> Result<Self, String> {
+        let lower = spec.trim().to_lowercase();
+        match lower.as_str() {
+            "e" => {
+                let (numerator, denominator) = decimal_fraction(E).unwrap();
+                return Ok(RealRadix::approximation("e", numerator, denominator, None));
+            }
+            "pi" | "π" => {
+                let (numerator, denominator) = decimal_fraction(PI).unwrap();
+                return Ok(RealRadix::approximation("π", numerator, denominator, None));
+            }
+            "phi" | "φ" => {
+                // φ = (1 + √5) / 2
+                let root5 = RealRadix::square_root(5)?;
+                let numerator = &root5.denominator + &root5.numerator;
+                let denominator = &root5.denominator * &BigUint::from_u64(2);
+                let phi = QuadraticRadix {
+                    p: 1,
+                    q: 1,
+                    r: 2,
+                    n: 5,
+                };
+                return Ok(RealRadix::approximation(
+                    "φ",
+                    numerator,
+                    denominator,
+                    Some(phi),
+                ));
+            }
+            _ => {}
+        }
+
+        let root = ["sqrt", "√"]
+            .iter()
+            .find_map(|prefix| lower.strip_prefix(prefix))
+            .map(|rest| rest.trim_start_matches('(').trim_end_matches(')'));
+        if let Some(root) = root {
+            let n = root
+                .parse::<u32>()
+                .map_err(|_| format!("Invalid square root radix '{}'", spec))?;
+            return RealRadix::square_root(n);
+        }
+
+        let invalid = || format!("Invalid radix '{}'", spec);
+        let (numerator, denominator) = match lower.split_once('/') {
+            Some((p, q)) => {
+                let (p, p_scale) = decimal_fraction(p.trim()).ok_or_else(invalid)?;
+                let (q, q_scale) = decimal_fraction(q.trim()).ok_or_else(invalid)?;
+                (&p * &q_scale, &q * &p_scale)
+            }
+            None => decimal_fraction(&lower).ok_or_else(invalid)?,
+        };
+        RealRadix::rational(lower, numerator, denominator)
+    }
+
+    /// How many digit values a greedy expansion uses: `⌈β⌉`, so base 3/2
+    /// writes 0 and 1 and base e writes 0, 1 and 2.
+    pub fn digit_count(&self) -> u32 {
+        let (floor, remainder) = self.numerator.divrem(&self.denominator);
+        let floor = floor
+            .to_u128()
+            .unwrap_or(u128::MAX)
+            .min(u32::MAX as u128 - 1) as u32;
+        if remainder.is_zero() {
+            floor
+        } else {
+            floor + 1
+        }
+    }
+}
+
+/// A greedy beta-expansion. `exact` is set when the digits stop before the
+/// precision limit; for e and π that is measured against the approximation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BetaExpansion {
+    pub digits: Number,
+    pub exact: bool,
+}
+
+/// Expands `number`, read in `from_base`, in `radix` by the greedy
+/// algorithm: each digit is the largest that keeps the remainder
+/// non-negative, down to `precision` fraction digits. Digits are truncated,
+/// never rounded.
+pub fn beta_expand(
+    number: &Number,
+    from_base: u32,
+    radix: &RealRadix,
+    precision: usize,
+) -> BetaExpansion {
+    let (value, value_denominator) = rational_value(number, from_base);
+    let (mut digits, top, truncated) = match &radix.quadratic {
+        Some(quadratic) => quadratic.expand(&value, &value_denominator, precision),
+        None => rational_expand(&value, &value_denominator, radix, precision),
+    };
+
+    let mut fractional = digits.split_off(top + 1);
+    while fractional.last() == Some(&0) {
+        fractional.pop();
+    }
+    BetaExpansion {
+        digits: Number {
+            negative: number.negative && !value.is_zero(),
+            ..Number::new(digits, fractional)
+        },
+        exact: !truncated,
+    }
+}
+
+/// The greedy digits of `value / value_denominator` in a rational radix, as
+/// for [`QuadraticRadix::expand`].
+fn rational_expand(
+    value: &BigUint,
+    value_denominator: &BigUint,
+    radix: &RealRadix,
+    precision: usize,
+) -> (Vec<u32>, usize, bool) {
+    let (p, q) = (&radix.numerator, &radix.denominator);
+
+    // The top place K is the largest with β^K <= x, or 0 for x < 1
+    let mut top = 0;
+    let (mut lower, mut upper) = (value_denominator * p, value * q);
+    while lower <= upper {
+        top += 1;
+        lower = &lower * p;
+        upper = &upper * q;
+    }
+
+    // Scale by S = b q^K p^P so that x and every β^j are integers: x S =
+    // a q^K p^P and β^j S = b q^(K-j) p^(P+j).
+    let mut remainder = &(value * &power(q, top)) * &power(p, precision);
+    let mut weight = value_denominator * &power(p, precision + top);
+    let mut digits = Vec::with_capacity(top + precision + 1);
+    for place in 0..=top + precision {
+        if place > 0 {
+            weight = (&weight * q).divrem(p).0;
+        }
+        let (digit, rest) = remainder.divrem(&weight);
+        digits.push(digit.to_u128().unwrap() as u32);
+        remainder = rest;
+    }
+    (digits, top, !remainder.is_zero())
+}
+
+/// Reads digits written in `radix`, each below [`RealRadix::digit_count`].
+pub fn parse_beta(value: &str, radix: &RealRadix, alphabet: &Alphabet) -> Result<Number, String> {
+    let number = parse_number_with(value, radix.digit_count(), alphabet)?;
+    if !number.repetend.is_empty() {
+        return Err(format!(
+            "Repeating digits are not supported in base {}",
+            radix.name
+        ));
+    }
+    Ok(number)
+}
+
+/// The value of digits written in `radix` as a fraction, exact for exact
+/// radices.
+pub fn beta_value(number: &Number, radix: &RealRadix) -> (BigUint, BigUint) {
+    let (p, q) = (&radix.numerator, &radix.denominator);
+    let integer = number.integer.len().max(1);
+    let padding = integer - number.integer.len();
+    let places = integer + number.fractional.len();
+
+    // Over the common denominator q^(I-1) p^F, the digit at place j counts
+    // p^(j+F) q^(I-1-j) with j running from I - 1 down to -F
+    let mut numerator = BigUint::zero();
+    let digits = number.integer.iter().chain(&number.fractional);
+    for (i, &digit) in digits.enumerate().map(|(i, d)| (i + padding, d)) {
+        if digit == 0 {
+            continue;
+        }
+        let term = &power(p, places - 1 - i) * &power(q, i);
+        numerator = &numerator + &(&term * &BigUint::from_u64(digit as u64));
+    }
+    let denominator = &power(q, integer - 1) * &power(p, number.fractional.len());
+    (numerator, denominator)
+}
+
+/// Converts digits written in `radix` to the ordinary base `to_base`.
+pub fn convert_from_beta(
+    number: &Number,
+    radix: &RealRadix,
+    to_base: u32,
+    precision: usize,
+    rounding: RoundingMode,
+) -> Number {
+    let (numerator, denominator) = beta_value(number, radix);
+    let negative = number.negative && !numerator.is_zero();
+    convert_rational(
+        negative,
+        &numerator,
+        &denominator,
+        to_base,
+        precision,
+        rounding,
+    )
+}
+
+/// Expands digits written in `radix` exactly in the ordinary `to_base`,
+/// marking any repetend.
+pub fn expand_from_beta(
+    number: &Number,
+    radix: &RealRadix,
+    to_base: u32,
+    max_digits: usize,
+) -> Expansion {
+    let (numerator, denominator) = beta_value(number, radix);
+    let negative = number.negative && !numerator.is_zero();
+    expand_rational(negative, &numerator, &denominator, to_base, max_digits)
+}
+
+/// Rewrites digits written in `radix` as an ordinary positional number,
+/// returning its base. Exact radices `p/q` land exactly in base `p * q`, whose
+/// powers every place value divides; otherwise the value is rounded toward
+/// zero to `fallback_digits` decimal places.
+pub fn beta_to_positional(
+    number: &Number,
+    radix: &RealRadix,
+    fallback_digits: usize,
+) -> (u32, Number) {
+    let base = (&radix.numerator * &radix.denominator).to_u128();
+    match base {
+        Some(base) if radix.exact && base <= u32::MAX as u128 => {
+            let places = number.fractional.len().max(number.integer.len());
+            let base = base as u32;
+            let digits = convert_from_beta(number, radix, base, places, RoundingMode::TowardZero);
+            (base, digits)
+        }
+        _ => {
+            let digits =
+                convert_from_beta(number, radix, 10, fallback_digits, RoundingMode::TowardZero);
+            (10, digits)
+        }
+    }
+}
@@ -0,0 +1,625 @@
+use std::cmp::Ordering;
+use std::ops::{Add, Mul, Sub};
+
+/// Arbitrary-precision unsigned integer stored as little-endian base-2^32 limbs.
+///
+/// The limb vector never has trailing zero limbs, so zero is the empty vector
+/// and derived equality and hashing are structural.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct BigUint {
+    limbs: Vec<u32>,
+}
+
+impl BigUint {
+    pub fn zero() -> Self {
+        BigUint { limbs: Vec::new() }
+    }
+
+    pub fn one() -> Self {
+        BigUint { limbs: vec![1] }
+    }
+
+    pub fn from_u64(value: u64) -> Self {
+        let mut n = BigUint {
+            limbs: vec![value as u32, (value >> 32) as u32],
+        };
+        n.normalize();
+        n
+    }
+
+    pub fn from_u128(value: u128) -> Self {
+        let mut n = BigUint {
+            limbs: (0..4).map(|i| (value >> (32 * i)) as u32).collect(),
+        };
+        n.normalize();
+        n
+    }
+
+    pub fn to_u128(&self) -> Option<u128> {
+        if self.limbs.len() > 4 {
+            return None;
+        }
+        Some(
+            self.limbs
+                .iter()
+                .rev()
+                .fold(0u128, |acc, &limb| (acc << 32) | limb as u128),
+        )
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    /// Number of significant bits; zero has none.
+    pub fn bits(&self) -> u64 {
+        match self.limbs.last() {
+            Some(&top) => self.limbs.len() as u64 * 32 - top.leading_zeros() as u64,
+            None => 0,
+        }
+    }
+
+    fn normalize(&mut self) {
+        while self.limbs.last() == Some(&0) {
+            self.limbs.pop();
+        }
+    }
+
+    pub fn mul_small(&mut self, factor: u32) {
+        let mut carry = 0u64;
+        for limb in self.limbs.iter_mut() {
+            let product = *limb as u64 * factor as u64 + carry;
+            *limb = product as u32;
+            carry = product >> 32;
+        }
+        if carry > 0 {
+            self.limbs.push(carry as u32);
+        }
+        self.normalize();
+    }
+
+    pub fn add_small(&mut self, addend: u32) {
+        let mut carry = addend as u64;
+        for limb in self.limbs.iter_mut() {
+            if carry == 0 {
+                break;
+            }
+            let sum = *limb as u64 + carry;
+            *limb = sum as u32;
+            carry = sum >> 32;
+        }
+        if carry > 0 {
+            self.limbs.push(carry as u32);
+        }
+    }
+
+    /// Divides in place by `divisor` and returns the remainder.
+    pub fn divrem_small(&mut self, divisor: u32) -> u32 {
+        assert!(divisor != 0, "division by zero");
+        let mut remainder = 0u64;
+        for limb in self.limbs.iter_mut().rev() {
+            let current = (remainder << 32) | *limb as u64;
+            *limb = (current / divisor as u64) as u32;
+            remainder = current % divisor as u64;
+        }
+        self.normalize();
+        remainder as u32
+    }
+
+    pub fn pow(base: u32, exponent: usize) -> Self {
+        let mut result = BigUint::one();
+        let mut square = BigUint::from_u64(base as u64);
+        let mut e = exponent;
+        while e > 0 {
+            if e & 1 == 1 {
+                result = &result * &square;
+            }
+            e >>= 1;
+            if e > 0 {
+                square = &square * &square;
+            }
+        }
+        result
+    }
+
+    /// Returns `(self / divisor, self % divisor)` using Knuth's algorithm D.
+    pub fn divrem(&self, divisor: &BigUint) -> (BigUint, BigUint) {
+        assert!(!divisor.is_zero(), "division by zero");
+        if self < divisor {
+            return (BigUint::zero(), self.clone());
+        }
+        if divisor.limbs.len() == 1 {
+            let mut quotient = self.clone();
+            let remainder = quotient.divrem_small(divisor.limbs[0]);
+            return (quotient, BigUint::from_u64(remainder as u64));
+        }
+
+        let shift = divisor.limbs.last().unwrap().leading_zeros();
+        let mut v = shl_limbs(&divisor.limbs, shift);
+        v.pop();
+        let mut u = shl_limbs(&self.limbs, shift);
+        let n = v.len();
+        let m = u.len() - n - 1;
+        let mut quotient = vec![0u32; m + 1];
+        let v_top = v[n - 1] as u64;
+        let v_next = v[n - 2] as u64;
+
+        for j in (0..=m).rev() {
+            let numerator = ((u[j + n] as u64) << 32) | u[j + n - 1] as u64;
+            let mut q_hat = numerator / v_top;
+            let mut r_hat = numerator % v_top;
+            while q_hat > u32::MAX as u64 || q_hat * v_next > ((r_hat << 32) | u[j + n - 2] as u64)
+            {
+                q_hat -= 1;
+                r_hat += v_top;
+                if r_hat > u32::MAX as u64 {
+                    break;
+                }
+            }
+
+            let mut borrow = 0i64;
+            let mut carry = 0u64;
+            for i in 0..n {
+                let product = q_hat * v[i] as u64 + carry;
+                carry = product >> 32;
+                let t = u[i + j] as i64 - borrow - (product & 0xFFFF_FFFF) as i64;
+                u[i + j] = t as u32;
+                borrow = (t < 0) as i64;
+            }
+            let t = u[j + n] as i64 - borrow - carry as i64;
+            u[j + n] = t as u32;
+
+            if t < 0 {
+                q_hat -= 1;
+                let mut carry = 0u64;
+                for i in 0..n {
+                    let sum = u[i + j] as u64 + v[i] as u64 + carry;
+                    u[i + j] = sum as u32;
+                    carry = sum >> 32;
+                }
+                u[j + n] = u[j + n].wrapping_add(carry as u32);
+            }
+            quotient[j] = q_hat as u32;
+        }
+
+        let mut quotient = BigUint { limbs: quotient };
+        quotient.normalize();
+        let mut remainder = BigUint {
+            limbs: shr_limbs(&u[..n], shift),
+        };
+        remainder.normalize();
+        (quotient, remainder)
+    }
+
+    /// Builds a value from most-significant-first digits in `base`. Long
+    /// inputs are split in half recursively and recombined as
+    /// `high * base^len(low) + low`, which with Karatsuba multiplication is
+    /// subquadratic.
+    pub fn from_digits(digits: &[u32], base: u32) -> Self {
+        let (chunk_len, chunk_base) = chunk_size(base);
+        if digits.len() < chunk_len * FROM_DIGITS_THRESHOLD {
+            return BigUint::from_digits_schoolbook(digits, base);
+        }
+
+        // powers[i] = base^(chunk_len * 2^i)
+        let mut powers = vec![BigUint::from_u64(chunk_base as u64)];
+        while chunk_len << powers.len() < digits.len() {
+            let last = powers.last().unwrap();
+            powers.push(last * last);
+        }
+        from_digits_recursive(digits, base, chunk_len, &powers)
+    }
+
+    /// [`BigUint::from_digits`] by repeated multiplication, which takes time
+    /// quadratic in the number of digits.
+    pub fn from_digits_schoolbook(digits: &[u32], base: u32) -> Self {
+        let (chunk_len, _) = chunk_size(base);
+        let mut result = BigUint::zero();
+        for chunk in digits.chunks(chunk_len) {
+            let mut scale = 1u32;
+            let mut value = 0u32;
+            for &digit in chunk {
+                scale *= base;
+                value = value * base + digit;
+            }
+            result.mul_small(scale);
+            result.add_small(value);
+        }
+        result
+    }
+
+    /// Returns the most-significant-first digits in `base`; zero is `[0]`.
+    /// Long values are divided by a precomputed power `base^k` near their
+    /// square root and both halves converted recursively, dividing through
+    /// Newton reciprocals so that the whole conversion is subquadratic.
+    pub fn to_digits(&self, base: u32) -> Vec<u32> {
+        if self.limbs.len() < TO_DIGITS_THRESHOLD {
+            return self.to_digits_schoolbook(base);
+        }
+
+        // powers[i] = base^(chunk_len * 2^i), up to the last one at most self
+        let (chunk_len, chunk_base) = chunk_size(base);
+        let mut powers = vec![BigUint::from_u64(chunk_base as u64)];
+        loop {
+            let last = powers.last().unwrap();
+            let square = last * last;
+            if square > *self {
+                break;
+            }
+            powers.push(square);
+        }
+        let tree: Vec<(BigUint, BigUint)> = powers
+            .into_iter()
+            .map(|power| {
+                let inverse = reciprocal(&power);
+                (power, inverse)
+            })
+            .collect();
+
+        let mut digits = Vec::new();
+        to_digits_recursive(self, base, chunk_len, &tree, tree.len() - 1, 0, &mut digits);
+        digits
+    }
+
+    /// [`BigUint::to_digits`] by repeated division, which takes time
+    /// quadratic in the number of digits.
+    pub fn to_digits_schoolbook(&self, base: u32) -> Vec<u32> {
+        if self.is_zero() {
+            return vec![0];
+        }
+
+        let (chunk_len, chunk_base) = chunk_size(base);
+        let mut num = self.clone();
+        let mut result = Vec::new();
+        while !num.is_zero() {
+            let mut chunk = num.divrem_small(chunk_base);
+            for _ in 0..chunk_len {
+                result.push(chunk % base);
+                chunk /= base;
+            }
+        }
+
+        while result.len() > 1 && result.last() == Some(&0) {
+            result.pop();
+        }
+        result.reverse();
+        result
+    }
+
+    fn shl_bits(&self, bits: u64) -> BigUint {
+        if self.is_zero() {
+            return BigUint::zero();
+        }
+        let mut limbs = vec![0u32; (bits / 32) as usize];
+        limbs.extend(shl_limbs(&self.limbs, (bits % 32) as u32));
+        let mut result = BigUint { limbs };
+        result.normalize();
+        result
+    }
+
+    fn shr_bits(&self, bits: u64) -> BigUint {
+        let skipped = (bits / 32) as usize;
+        if skipped >= self.limbs.len() {
+            return BigUint::zero();
+        }
+        let mut result = BigUint {
+            limbs: shr_limbs(&self.limbs[skipped..], (bits % 32) as u32),
+        };
+        result.normalize();
+        result
+    }
+}
+
+/// Below this many limbs, conversion by repeated division beats splitting.
+const TO_DIGITS_THRESHOLD: usize = 128;
+
+/// Below this many limb-sized chunks of digits, conversion by repeated
+/// multiplication beats splitting.
+const FROM_DIGITS_THRESHOLD: usize = 1024;
+
+fn from_digits_recursive(
+    digits: &[u32],
+    base: u32,
+    chunk_len: usize,
+    powers: &[BigUint],
+) -> BigUint {
+    if digits.len() < chunk_len * FROM_DIGITS_THRESHOLD {
+        return BigUint::from_digits_schoolbook(digits, base);
+    }
+    // Split off the largest power-of-two number of chunks below the length
+    let level = (0..powers.len())
+        .rev()
+        .find(|&level| chunk_len << level < digits.len())
+        .unwrap();
+    let (high, low) = digits.split_at(digits.len() - (chunk_len << level));
+    let high = from_digits_recursive(high, base, chunk_len, powers);
+    let low = from_digits_recursive(low, base, chunk_len, powers);
+    &(&high * &powers[level]) + &low
+}
+
+/// Appends the digits of `value`, left-padded with zeros to `width` digits,
+/// given `value < tree[level].0²`.
+fn to_digits_recursive(
+    value: &BigUint,
+    base: u32,
+    chunk_len: usize,
+    tree: &[(BigUint, BigUint)],
+    level: usize,
+    width: usize,
+    digits: &mut Vec<u32>,
+) {
+    if value.limbs.len() < TO_DIGITS_THRESHOLD {
+        let written = value.to_digits_schoolbook(base);
+        digits.extend(std::iter::repeat_n(0, width.saturating_sub(written.len())));
+        digits.extend(written);
+        return;
+    }
+
+    let (power, inverse) = &tree[level];
+    if value < power {
+        to_digits_recursive(value, base, chunk_len, tree, level - 1, width, digits);
+        return;
+    }
+    let low_width = chunk_len << level;
+    let (high, low) = divrem_by_reciprocal(value, power, inverse);
+    let high_width = width.saturating_sub(low_width);
+    to_digits_recursive(&high, base, chunk_len, tree, level - 1, high_width, digits);
+    to_digits_recursive(&low, base, chunk_len, tree, level - 1, low_width, digits);
+}
+
+/// Below this many limbs, a reciprocal is found by long division.
+const RECIPROCAL_THRESHOLD: usize = 32;
+
+/// Approximates `2^2m / divisor`, with `m` the bit length of `divisor`, to
+/// within a few units by Newton's iteration `x' = x + x (2^2m - divisor x) /
+/// 2^2m`, starting from the reciprocal of the top half of the divisor. Each
+/// step doubles the number of correct bits.
+fn reciprocal(divisor: &BigUint) -> BigUint {
+    let m = divisor.bits();
+    let target = BigUint::one().shl_bits(2 * m);
+    if divisor.limbs.len() < RECIPROCAL_THRESHOLD {
+        return target.divrem(divisor).0;
+    }
+
+    // A few guard bits keep the starting error well inside the halved width
+    let h = m.div_ceil(2) + 4;
+    let top = divisor.shr_bits(m - h);
+    let x = reciprocal(&top).shl_bits(m - h);
+    let product = &x * divisor;
+    if product <= target {
+        let error = &target - &product;
+        &x + &(&x * &error).shr_bits(2 * m)
+    } else {
+        let error = &product - &target;
+        let mut step = (&x * &error).shr_bits(2 * m);
+        step.add_small(1);
+        &x - &step
+    }
+}
+
+/// Divides `value < divisor²` using `inverse ≈ reciprocal(divisor)`. Only
+/// the top half of `value` enters the estimate, which is then corrected.
+fn divrem_by_reciprocal(
+    value: &BigUint,
+    divisor: &BigUint,
+    inverse: &BigUint,
+) -> (BigUint, BigUint) {
+    let m = divisor.bits();
+    let mut quotient = (&value.shr_bits(m - 2) * inverse).shr_bits(m + 2);
+    let mut product = &quotient * divisor;
+    while product > *value {
+        quotient = &quotient - &BigUint::one();
+        product = &product - divisor;
+    }
+    let mut remainder = value - &product;
+    while remainder >= *divisor {
+        quotient.add_small(1);
+        remainder = &remainder - divisor;
+    }
+    (quotient, remainder)
+}
+
+/// Largest `k` with `base^k` fitting in a limb, together with `base^k`.
+fn chunk_size(base: u32) -> (usize, u32) {
+    let mut len = 1;
+    let mut power = base;
+    while let Some(next) = power.checked_mul(base) {
+        power = next;
+        len += 1;
+    }
+    (len, power)
+}
+
+/// Shifts left by `shift < 32` bits, always appending one extra high limb.
+fn shl_limbs(limbs: &[u32], shift: u32) -> Vec<u32> {
+    let mut result = Vec::with_capacity(limbs.len() + 1);
+    let mut carry = 0u32;
+    for &limb in limbs {
+        if shift == 0 {
+            result.push(limb);
+        } else {
+            result.push((limb << shift) | carry);
+            carry = limb >> (32 - shift);
+        }
+    }
+    result.push(carry);
+    result
+}
+
+fn shr_limbs(limbs: &[u32], shift: u32) -> Vec<u32> {
+    if shift == 0 {
+        return limbs.to_vec();
+    }
+    let mut result = vec![0u32; limbs.len()];
+    for i in 0..limbs.len() {
+        let high = limbs.get(i + 1).map_or(0, |&next| next << (32 - shift));
+        result[i] = (limbs[i] >> shift) | high;
+    }
+    result
+}
+
+impl Ord for BigUint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.limbs
+            .len()
+            .cmp(&other.limbs.len())
+            .then_with(|| self.limbs.iter().rev().cmp(other.limbs.iter().rev()))
+    }
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Add for &BigUint {
+    type Output = BigUint;
+
+    fn add(self, other: &BigUint) -> BigUint {
+        let (long, short) = if self.limbs.len() >= other.limbs.len() {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        let mut limbs = Vec::with_capacity(long.limbs.len() + 1);
+        let mut carry = 0u64;
+        for (i, &limb) in long.limbs.iter().enumerate() {
+            let sum = limb as u64 + *short.limbs.get(i).unwrap_or(&0) as u64 + carry;
+            limbs.push(sum as u32);
+            carry = sum >> 32;
+        }
+        if carry > 0 {
+            limbs.push(carry as u32);
+        }
+        BigUint { limbs }
+    }
+}
+
+impl Sub for &BigUint {
+    type Output = BigUint;
+
+    /// Panics if `other > self`.
+    fn sub(self, other: &BigUint) -> BigUint {
+        assert!(*self >= *other, "subtraction underflow");
+        let mut limbs = Vec::with_capacity(self.limbs.len());
+        let mut borrow = 0i64;
+        for (i, &limb) in self.limbs.iter().enumerate() {
+            let diff = limb as i64 - *other.limbs.get(i).unwrap_or(&0) as i64 - borrow;
+            limbs.push(diff as u32);
+            borrow = (diff < 0) as i64;
+        }
+        let mut result = BigUint { limbs };
+        result.normalize();
+        result
+    }
+}
+
+impl Mul for &BigUint {
+    type Output = BigUint;
+
+    fn mul(self, other: &BigUint) -> BigUint {
+        if self.is_zero() || other.is_zero() {
+            return BigUint::zero();
+        }
+        let mut result = BigUint {
+            limbs: mul_limbs(&self.limbs, &other.limbs),
+        };
+        result.normalize();
+        result
+    }
+}
+
+/// Below this many limbs in the shorter factor, schoolbook multiplication
+/// beats Karatsuba.
+const KARATSUBA_THRESHOLD: usize = 32;
+
+fn mul_schoolbook(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut limbs = vec![0u32; a.len() + b.len()];
+    for (i, &x) in a.iter().enumerate() {
+        let mut carry = 0u64;
+        for (limb, &y) in limbs[i..i + b.len()].iter_mut().zip(b) {
+            let product = x as u64 * y as u64 + *limb as u64 + carry;
+            *limb = product as u32;
+            carry = product >> 32;
+        }
+        limbs[i + b.len()] = carry as u32;
+    }
+    limbs
+}
+
+/// Multiplies limb slices by Karatsuba's method: with `x = x1 B + x0` and
+/// `y = y1 B + y0`, three half-size products give
+/// `xy = z2 B² + ((x0 + x1)(y0 + y1) - z2 - z0) B + z0`. The result has
+/// `a.len() + b.len()` limbs.
+fn mul_limbs(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    if short.len() < KARATSUBA_THRESHOLD {
+        return mul_schoolbook(long, short);
+    }
+
+    let half = long.len() / 2;
+    let mut result = vec![0u32; a.len() + b.len()];
+    if short.len() <= half {
+        // Too unbalanced to split both: multiply the short factor by each
+        // half of the long one
+        let (low, high) = long.split_at(half);
+        add_limbs_at(&mut result, &mul_limbs(low, short), 0);
+        add_limbs_at(&mut result, &mul_limbs(high, short), half);
+        return result;
+    }
+
+    let (x0, x1) = long.split_at(half);
+    let (y0, y1) = short.split_at(half);
+    let z0 = mul_limbs(x0, y0);
+    let z2 = mul_limbs(x1, y1);
+    let mut z1 = mul_limbs(&sum_limbs(x0, x1), &sum_limbs(y0, y1));
+    sub_limbs(&mut z1, &z0);
+    sub_limbs(&mut z1, &z2);
+
+    add_limbs_at(&mut result, &z0, 0);
+    add_limbs_at(&mut result, &z1, half);
+    add_limbs_at(&mut result, &z2, 2 * half);
+    result
+}
+
+fn sum_limbs(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut sum = a.to_vec();
+    sum.resize(a.len().max(b.len()) + 1, 0);
+    add_limbs_at(&mut sum, b, 0);
+    sum
+}
+
+/// Adds `value` into `target` starting at limb `offset`. High zero limbs of
+/// `value` may run past the end of `target`, but the sum itself must fit.
+fn add_limbs_at(target: &mut [u32], value: &[u32], offset: usize) {
+    let mut carry = 0u64;
+    for (i, &limb) in value.iter().enumerate() {
+        let Some(slot) = target.get_mut(offset + i) else {
+            debug_assert!(limb == 0 && carry == 0, "sum overflows the target");
+            continue;
+        };
+        let sum = *slot as u64 + limb as u64 + carry;
+        *slot = sum as u32;
+        carry = sum >> 32;
+    }
+    let mut i = offset + value.len();
+    while carry > 0 {
+        let sum = target[i] as u64 + carry;
+        target[i] = sum as u32;
+        carry = sum >> 32;
+        i += 1;
+    }
+}
+
+/// Subtracts `value` from `target` in place; `target` must be the larger.
+fn sub_limbs(target: &mut [u32], value: &[u32]) {
+    let mut borrow = 0i64;
+    let mut i = 0;
+    while i < value.len() || borrow > 0 {
+        let diff = target[i] as i64 - value.get(i).copied().unwrap_or(0) as i64 - borrow;
+        target[i] = diff as u32;
+        borrow = (diff < 0) as i64;
+        i += 1;
+    }
+}
@@ -0,0 +1,135 @@
+use crate::alphabet::Alphabet;
+use crate::bigint::BigUint;
+use crate::formatter::{FormatOptions, format_number_with};
+use crate::number::Number;
+use crate::parser::{char_to_digit, split_sign, strip_separators};
+
+const SPREADSHEET: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+const ONE_UP: &str = "123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// The longest tally written in bijective base 1.
+pub const MAX_TALLY: u32 = 100_000;
+
+/// An integer in bijective base `k`, whose digits run from 1 to `k` with no
+/// zero. Zero has no digits at all.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Bijective {
+    pub negative: bool,
+    pub digits: Vec<u32>,
+}
+
+impl Bijective {
+    /// Writes `±magnitude` in bijective base `base`.
+    pub fn from_integer(negative: bool, magnitude: &BigUint, base: u32) -> Result<Self, String> {
+        if base == 0 {
+            return Err("Bijective bases start at 1".to_string());
+        }
+        if base == 1 {
+            let count = magnitude
+                .to_u128()
+                .filter(|&count| count <= MAX_TALLY as u128)
+                .ok_or_else(|| format!("A tally is limited to {} marks", MAX_TALLY))?;
+            return Ok(Bijective {
+                negative: negative && count > 0,
+                digits: vec![1; count as usize],
+            });
+        }
+
+        let mut magnitude = magnitude.clone();
+        let mut digits = Vec::new();
+        while !magnitude.is_zero() {
+            let remainder = magnitude.divrem_small(base);
+            if remainder == 0 {
+                // n = qk = (q - 1)k + k
+                digits.push(base);
+                magnitude = &magnitude - &BigUint::one();
+            } else {
+                digits.push(remainder);
+            }
+        }
+        digits.reverse();
+        Ok(Bijective {
+            negative: negative && !digits.is_empty(),
+            digits,
+        })
+    }
+
+    /// The magnitude of the value.
+    pub fn magnitude(&self, base: u32) -> BigUint {
+        let mut value = BigUint::zero();
+        for &digit in &self.digits {
+            value.mul_small(base);
+            value.add_small(digit);
+        }
+        value
+    }
+}
+
+/// The default symbols of bijective base `base`, where symbol `i` stands for
+/// digit `i + 1`: `A-Z` for base 26, as in spreadsheet columns, and
+/// `1-9A-Z` otherwise, up to base 35.
+pub fn bijective_alphabet(base: u32) -> Result<Alphabet, String> {
+    let symbols = match base {
+        0 => return Err("Bijective bases start at 1".to_string()),
+        26 => SPREADSHEET,
+        36.. => {
+            return Err(format!(
+                "Bijective base {} has no default symbols; give an --alphabet of {} symbols",
+                base, base
+            ));
+        }
+        _ => ONE_UP,
+    };
+    Alphabet::new(symbols)?.case_insensitive()
+}
+
+pub fn check_bijective_base(base: u32, alphabet: &Alphabet) -> Result<(), String> {
+    if base == 0 {
+        return Err("Bijective bases start at 1".to_string());
+    }
+    if base > alphabet.max_base() {
+        return Err(format!(
+            "Bijective base {} needs {} digit symbols, but the alphabet has {}",
+            base,
+            base,
+            alphabet.max_base()
+        ));
+    }
+    Ok(())
+}
+
+/// Reads a bijective integer written with `alphabet`, where symbol `i` is
+/// digit `i + 1`. An empty value is zero.
+pub fn parse_bijective(value: &str, base: u32, alphabet: &Alphabet) -> Result<Bijective, String> {
+    check_bijective_base(base, alphabet)?;
+    let value = strip_separators(value, alphabet)?;
+    let (sign, digits) = split_sign(&value, alphabet);
+    if digits.contains('.') {
+        return Err("Bijective numeration has no fractional digits".to_string());
+    }
+
+    let digits = digits
+        .chars()
+        .map(|c| char_to_digit(c, base, alphabet).map(|index| index + 1))
+        .collect::<Result<Vec<u32>, String>>()?;
+    Ok(Bijective {
+        negative: sign == "-" && !digits.is_empty(),
+        digits,
+    })
+}
+
+/// Writes a bijective integer with the symbols of `options.alphabet`, where
+/// symbol `i` is digit `i + 1`. Zero is the empty string.
+pub fn format_bijective(number: &Bijective, options: &FormatOptions) -> String {
+    if number.digits.is_empty() {
+        return String::new();
+    }
+    let indices = number.digits.iter().map(|&digit| digit - 1).collect();
+    format_number_with(
+        &Number {
+            negative: number.negative,
+            ..Number::new(indices, Vec::new())
+        },
+        options,
+    )
+}
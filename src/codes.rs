@@ -0,0 +1,147 @@
+use crate::bigint::BigUint;
+
+/// A binary code for non-negative integers. Apart from Gray code, each
+/// encodes the decimal digits one at a time in a fixed-width group of bits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinaryCode {
+    /// Reflected binary Gray code: consecutive values differ in one bit.
+    Gray,
+    /// Packed BCD, the 8421 code: a 4-bit nibble per decimal digit.
+    PackedBcd,
+    /// Unpacked BCD: a byte per decimal digit, with a zero high nibble.
+    UnpackedBcd,
+    /// Excess-3: each decimal digit plus 3, in a nibble.
+    Excess3,
+    /// 2-of-5 with weights 7-4-2-1-0, as in POSTNET: exactly two of every
+    /// five bits are set, and 0 is `11000`.
+    TwoOfFive,
+    /// The self-complementing Aiken 2421 code.
+    Aiken2421,
+}
+
+const TWO_OF_FIVE: [u32; 10] = [
+    0b11000, 0b00011, 0b00101, 0b00110, 0b01001, 0b01010, 0b01100, 0b10001, 0b10010, 0b10100,
+];
+
+impl BinaryCode {
+    pub fn name(&self) -> &'static str {
+        match self {
+            BinaryCode::Gray => "Gray code",
+            BinaryCode::PackedBcd => "packed BCD",
+            BinaryCode::UnpackedBcd => "unpacked BCD",
+            BinaryCode::Excess3 => "excess-3",
+            BinaryCode::TwoOfFive => "2-of-5 code",
+            BinaryCode::Aiken2421 => "2421 code",
+        }
+    }
+
+    /// The bits per decimal digit, or `None` for Gray code.
+    pub fn group_width(&self) -> Option<usize> {
+        match self {
+            BinaryCode::Gray => None,
+            BinaryCode::PackedBcd | BinaryCode::Excess3 | BinaryCode::Aiken2421 => Some(4),
+            BinaryCode::TwoOfFive => Some(5),
+            BinaryCode::UnpackedBcd => Some(8),
+        }
+    }
+
+    fn encode_digit(&self, digit: u32) -> u32 {
+        match self {
+            BinaryCode::Excess3 => digit + 3,
+            BinaryCode::TwoOfFive => TWO_OF_FIVE[digit as usize],
+            BinaryCode::Aiken2421 if digit >= 5 => digit + 6,
+            _ => digit,
+        }
+    }
+
+    fn decode_group(&self, group: u32) -> Option<u32> {
+        match self {
+            BinaryCode::Excess3 => (3..=12).contains(&group).then(|| group - 3),
+            BinaryCode::TwoOfFive => TWO_OF_FIVE
+                .iter()
+                .position(|&code| code == group)
+                .map(|digit| digit as u32),
+            BinaryCode::Aiken2421 => match group {
+                0..=4 => Some(group),
+                11..=15 => Some(group - 6),
+                _ => None,
+            },
+            _ => (group <= 9).then_some(group),
+        }
+    }
+}
+
+fn gray_encode(binary: &[u32]) -> Vec<u32> {
+    let mut previous = 0;
+    binary
+        .iter()
+        .map(|&bit| {
+            let gray = bit ^ previous;
+            previous = bit;
+            gray
+        })
+        .collect()
+}
+
+fn gray_decode(gray: &[u32]) -> Vec<u32> {
+    let mut previous = 0;
+    gray.iter()
+        .map(|&bit| {
+            previous ^= bit;
+            previous
+        })
+        .collect()
+}
+
+/// Writes each group in `width` bits, most significant first.
+fn to_bits(groups: impl Iterator<Item = u32>, width: usize) -> Vec<u32> {
+    groups
+        .flat_map(|group| (0..width).rev().map(move |bit| (group >> bit) & 1))
+        .collect()
+}
+
+/// Encodes a non-negative integer, most significant bit first. Digit codes
+/// take the decimal digits of `value`, as `convert_integer_part` gives them.
+pub fn encode_binary_code(value: &BigUint, code: BinaryCode) -> Vec<u32> {
+    match code.group_width() {
+        None => gray_encode(&value.to_digits(2)),
+        Some(width) => {
+            let digits = value.to_digits(10);
+            to_bits(digits.iter().map(|&digit| code.encode_digit(digit)), width)
+        }
+    }
+}
+
+/// Decodes bits written in `code`, rejecting groups that encode no decimal
+/// digit, such as the BCD nibbles `1010` to `1111`.
+pub fn decode_binary_code(bits: &[u32], code: BinaryCode) -> Result<BigUint, String> {
+    if let Some(&bit) = bits.iter().find(|&&bit| bit > 1) {
+        return Err(format!("Bits of {} are 0 or 1, found {}", code.name(), bit));
+    }
+    if bits.is_empty() {
+        return Err(format!("Empty {}", code.name()));
+    }
+    let Some(width) = code.group_width() else {
+        return Ok(BigUint::from_digits(&gray_decode(bits), 2));
+    };
+    if !bits.len().is_multiple_of(width) {
+        return Err(format!(
+            "Expected a multiple of {} bits in {}, got {}",
+            width,
+            code.name(),
+            bits.len()
+        ));
+    }
+
+    let mut value = BigUint::zero();
+    for group in bits.chunks(width) {
+        let pattern = group.iter().fold(0, |pattern, &bit| pattern << 1 | bit);
+        let digit = code.decode_group(pattern).ok_or_else(|| {
+            let written: String = group.iter().map(|bit| bit.to_string()).collect();
+            format!("Invalid {} group {}", code.name(), written)
+        })?;
+        value.mul_small(10);
+        value.add_small(digit);
+    }
+    Ok(value)
+}
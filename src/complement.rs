@@ -0,0 +1,116 @@
+use crate::bigint::BigUint;
+use crate::number::Number;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SignedEncoding {
+    #[default]
+    TwosComplement,
+    OnesComplement,
+    SignMagnitude,
+}
+
+impl SignedEncoding {
+    pub fn name(self) -> &'static str {
+        match self {
+            SignedEncoding::TwosComplement => "two's complement",
+            SignedEncoding::OnesComplement => "one's complement",
+            SignedEncoding::SignMagnitude => "sign-magnitude",
+        }
+    }
+}
+
+/// The widest pattern accepted, in bits.
+pub const MAX_BITS: u32 = 65_536;
+
+fn check_width(bits: u32) -> Result<(), String> {
+    if bits == 0 {
+        return Err("Bit width must be at least 1".to_string());
+    }
+    if bits > MAX_BITS {
+        return Err(format!("Bit width must be at most {}", MAX_BITS));
+    }
+    Ok(())
+}
+
+/// Number of `base` digits needed to show every `bits`-wide pattern.
+pub(crate) fn pattern_width(bits: u32, base: u32) -> usize {
+    let all_ones = &BigUint::pow(2, bits as usize) - &BigUint::one();
+    all_ones.to_digits(base).len()
+}
+
+/// Renders a signed integer in `base` as its `bits`-wide pattern, zero-padded
+/// to the full width and written in the same base.
+pub fn encode_signed(
+    number: &Number,
+    base: u32,
+    bits: u32,
+    encoding: SignedEncoding,
+) -> Result<Vec<u32>, String> {
+    check_width(bits)?;
+
+    let magnitude = number.integer_value(base)?;
+    let negative = number.negative && !magnitude.is_zero();
+    let half = BigUint::pow(2, bits as usize - 1);
+    let modulus = BigUint::pow(2, bits as usize);
+
+    let fits = match (negative, encoding) {
+        (true, SignedEncoding::TwosComplement) => magnitude <= half,
+        _ => magnitude < half,
+    };
+    if !fits {
+        return Err(format!(
+            "Overflow: value is outside the {}-bit {} range",
+            bits,
+            encoding.name()
+        ));
+    }
+
+    let pattern = if !negative {
+        magnitude
+    } else {
+        match encoding {
+            SignedEncoding::TwosComplement => &modulus - &magnitude,
+            SignedEncoding::OnesComplement => &(&modulus - &BigUint::one()) - &magnitude,
+            SignedEncoding::SignMagnitude => &half + &magnitude,
+        }
+    };
+
+    let mut digits = pattern.to_digits(base);
+    let width = pattern_width(bits, base);
+    if digits.len() < width {
+        digits.splice(0..0, vec![0; width - digits.len()]);
+    }
+    Ok(digits)
+}
+
+/// Interprets `digits` in `base` as a `bits`-wide pattern and returns the
+/// signed integer it encodes, still written in `base`.
+pub fn decode_signed(
+    digits: &[u32],
+    base: u32,
+    bits: u32,
+    encoding: SignedEncoding,
+) -> Result<Number, String> {
+    check_width(bits)?;
+
+    let pattern = BigUint::from_digits(digits, base);
+    if pattern.bits() > bits as u64 {
+        return Err(format!("Overflow: pattern does not fit in {} bits", bits));
+    }
+
+    let half = BigUint::pow(2, bits as usize - 1);
+    let modulus = BigUint::pow(2, bits as usize);
+    if pattern < half {
+        return Ok(Number::new(pattern.to_digits(base), Vec::new()));
+    }
+
+    let magnitude = match encoding {
+        SignedEncoding::TwosComplement => &modulus - &pattern,
+        SignedEncoding::OnesComplement => &(&modulus - &BigUint::one()) - &pattern,
+        SignedEncoding::SignMagnitude => &pattern - &half,
+    };
+    Ok(Number {
+        negative: !magnitude.is_zero(),
+        ..Number::new(magnitude.to_digits(base), Vec::new())
+    })
+}
@@ -0,0 +1,341 @@
+use crate::alphabet::Alphabet;
+use crate::bigint::BigUint;
+use crate::converter::{RoundingMode, convert_rational, place_point, rational_value, rounds_up};
+use crate::formatter::format_number;
+use crate::negabase::{Signed, add_signed, mul_signed, negabase_rational};
+use crate::number::Number;
+use crate::parser::{parse_number_full, parse_number_with};
+
+/// A Gaussian integer as its real and imaginary parts.
+type Gaussian = (Signed, Signed);
+
+fn small(value: i64) -> Signed {
+    (value < 0, BigUint::from_u64(value.unsigned_abs()))
+}
+
+fn negate((negative, magnitude): Signed) -> Signed {
+    (!negative && !magnitude.is_zero(), magnitude)
+}
+
+fn gaussian_mul((a, b): &Gaussian, (c, d): &Gaussian) -> Gaussian {
+    let real = add_signed(
+        mul_signed(a.clone(), c.clone()),
+        negate(mul_signed(b.clone(), d.clone())),
+    );
+    let imaginary = add_signed(
+        mul_signed(a.clone(), d.clone()),
+        mul_signed(b.clone(), c.clone()),
+    );
+    (real, imaginary)
+}
+
+fn gaussian_pow(base: &Gaussian, exponent: usize) -> Gaussian {
+    let mut result = (small(1), small(0));
+    for _ in 0..exponent {
+        result = gaussian_mul(&result, base);
+    }
+    result
+}
+
+/// A complex base whose digits `0..digit_count()` write every complex
+/// number without a sign.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ComplexBase {
+    /// Base `-n+i`, with digits `0..=n²`; `-1+i` writes every Gaussian
+    /// integer in binary.
+    NegativePlusI(u32),
+    /// Base `ni`, with digits `0..n²`; `2i` is Knuth's quater-imaginary base.
+    Imaginary(u32),
+}
+
+impl ComplexBase {
+    /// Reads `-n+i` (such as `-1+i` or `-2+i`) or `ni` (such as `2i`).
+    pub fn parse(spec: &str) -> Result<Self, String> {
+        let spec: String = spec.split_whitespace().collect::<String>().to_lowercase();
+        let invalid = || format!("Invalid complex base '{}': expected -n+i or ni", spec);
+        let base = if let Some(n) = spec.strip_suffix("+i").and_then(|s| s.strip_prefix('-')) {
+            let n = n.parse::<u32>().map_err(|_| invalid())?;
+            if n == 0 {
+                return Err("Base i cannot write every complex number".to_string());
+            }
+            ComplexBase::NegativePlusI(n)
+        } else if let Some(n) = spec.strip_suffix('i') {
+            let n = n.parse::<u32>().map_err(|_| invalid())?;
+            if n < 2 {
+                return Err(format!("Base {}i needs n of at least 2", n));
+            }
+            ComplexBase::Imaginary(n)
+        } else {
+            return Err(invalid());
+        };
+        let (ComplexBase::NegativePlusI(n) | ComplexBase::Imaginary(n)) = base;
+        if n > 256 || base.digit_count() > 65_536 {
+            return Err(format!("Complex base {} is too large", spec));
+        }
+        Ok(base)
+    }
+
+    pub fn name(&self) -> String {
+        match self {
+            ComplexBase::NegativePlusI(n) => format!("-{}+i", n),
+            ComplexBase::Imaginary(n) => format!("{}i", n),
+        }
+    }
+
+    /// The number of digit values, `|β|²` for `-n+i` and `ni` alike.
+    pub fn digit_count(&self) -> u32 {
+        match *self {
+            ComplexBase::NegativePlusI(n) => n * n + 1,
+            ComplexBase::Imaginary(n) => n * n,
+        }
+    }
+
+    fn radix(&self) -> Gaussian {
+        match *self {
+            ComplexBase::NegativePlusI(n) => (small(-(n as i64)), small(1)),
+            ComplexBase::Imaginary(n) => (small(0), small(n as i64)),
+        }
+    }
+}
+
+/// A complex number as decimal real and imaginary parts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ComplexNumber {
+    pub real: Number,
+    pub imaginary: Number,
+}
+
+/// Reads a decimal complex number such as `3+4i`, `-2.5i`, `i` or `7`.
+pub fn parse_complex(value: &str) -> Result<ComplexNumber, String> {
+    let value: String = value.split_whitespace().collect();
+    let Some(body) = value.strip_suffix('i') else {
+        return Ok(ComplexNumber {
+            real: parse_number_full(&value, 10)?,
+            imaginary: Number::new(vec![0], Vec::new()),
+        });
+    };
+
+    // The imaginary part starts at the last sign that is not the leading one
+    let split = body
+        .char_indices()
+        .skip(1)
+        .filter(|&(_, c)| c == '+' || c == '-')
+        .map(|(index, _)| index)
+        .last()
+        .unwrap_or(0);
+    let (real, imaginary) = body.split_at(split);
+    let imaginary = match imaginary {
+        "" | "+" => "1".to_string(),
+        "-" => "-1".to_string(),
+        digits => digits.to_string(),
+    };
+    let real = if real.is_empty() { "0" } else { real };
+    Ok(ComplexNumber {
+        real: parse_number_full(real, 10)?,
+        imaginary: parse_number_full(&imaginary, 10)?,
+    })
+}
+
+/// Writes a complex number as `a+bi`, leaving out a zero part and a unit
+/// coefficient: `3+4i`, `-i`, `2.5`.
+pub fn format_complex(value: &ComplexNumber) -> String {
+    let real = format_number(&value.real);
+    if value.imaginary.is_zero() {
+        return real;
+    }
+    let magnitude = format_number(&Number {
+        negative: false,
+        ..value.imaginary.clone()
+    });
+    let coefficient = if magnitude == "1" { "" } else { &magnitude };
+    let sign = if value.imaginary.negative { "-" } else { "+" };
+    if value.real.is_zero() {
+        let sign = sign.trim_start_matches('+');
+        format!("{}{}i", sign, coefficient)
+    } else {
+        format!("{}{}{}i", real, sign, coefficient)
+    }
+}
+
+/// Reads digits written in a complex base, each below `digit_count()`.
+pub fn parse_complex_base(
+    value: &str,
+    base: &ComplexBase,
+    alphabet: &Alphabet,
+) -> Result<Number, String> {
+    let number = parse_number_with(value, base.digit_count(), alphabet)?;
+    if !number.repetend.is_empty() {
+        return Err(format!(
+            "Repeating digits are not supported in base {}",
+            base.name()
+        ));
+    }
+    Ok(number)
+}
+
+/// Rounds the signed fraction `numerator / denominator` to an integer.
+fn round_signed(
+    (negative, numerator): Signed,
+    denominator: &BigUint,
+    rounding: RoundingMode,
+) -> Signed {
+    let (mut magnitude, remainder) = numerator.divrem(denominator);
+    let last_digit = magnitude.clone().divrem_small(2);
+    if rounds_up(rounding, &remainder, denominator, last_digit) {
+        magnitude.add_small(1);
+    }
+    (negative && !magnitude.is_zero(), magnitude)
+}
+
+/// Writes a Gaussian integer in base `-n+i`, least significant digit first.
+fn negative_plus_i_integer((mut real, mut imaginary): Gaussian, n: u32) -> Vec<u32> {
+    let norm = n * n + 1;
+    let mut digits = Vec::new();
+    while !real.1.is_zero() || !imaginary.1.is_zero() {
+        // i ≡ n modulo -n+i, so a + bi leaves the remainder a + bn
+        let (negative, residue) =
+            add_signed(real.clone(), mul_signed(small(n as i64), imaginary.clone()));
+        let remainder = residue.clone().divrem_small(norm);
+        let digit = if negative && remainder != 0 {
+            norm - remainder
+        } else {
+            remainder
+        };
+        digits.push(digit);
+
+        // (z - d) / (-n+i) = (z - d)(-n-i) / (n² + 1)
+        let x = add_signed(real, small(-(digit as i64)));
+        let y = imaginary;
+        let n = small(n as i64);
+        let mut next_real = add_signed(negate(mul_signed(n.clone(), x.clone())), y.clone());
+        let mut next_imaginary = add_signed(negate(x), negate(mul_signed(n, y)));
+        next_real.1.divrem_small(norm);
+        next_imaginary.1.divrem_small(norm);
+        real = (next_real.0 && !next_real.1.is_zero(), next_real.1);
+        imaginary = (
+            next_imaginary.0 && !next_imaginary.1.is_zero(),
+            next_imaginary.1,
+        );
+    }
+    digits
+}
+
+/// The digit of a base-`-b` number at `place`, counting fraction places as
+/// negative.
+fn digit_at(number: &Number, place: i64) -> u32 {
+    if place >= 0 {
+        let place = place as usize;
+        let length = number.integer.len();
+        if place < length {
+            number.integer[length - 1 - place]
+        } else {
+            0
+        }
+    } else {
+        let index = (-place - 1) as usize;
+        number.fractional.get(index).copied().unwrap_or(0)
+    }
+}
+
+/// Writes a complex number in a complex base with at most `precision`
+/// fraction digits, rounding the value scaled by `β^precision`. No sign is
+/// needed: every complex number has digits of its own.
+pub fn to_complex_base(
+    value: &ComplexNumber,
+    base: &ComplexBase,
+    precision: usize,
+    rounding: RoundingMode,
+) -> Number {
+    let (real, real_denominator) = rational_value(&value.real, 10);
+    let (imaginary, imaginary_denominator) = rational_value(&value.imaginary, 10);
+    let real_negative = value.real.negative;
+    let imaginary_negative = value.imaginary.negative;
+
+    let digits: Vec<u32> = match *base {
+        ComplexBase::Imaginary(n) => {
+            // (ni)^2m = (-n²)^m and (ni)^(2m+1) = ni (-n²)^m: the real part
+            // is base -n² in the even places and the imaginary part over n
+            // in the odd places, place -1 included
+            let b = n * n;
+            let even = negabase_rational(
+                real_negative,
+                &real,
+                &real_denominator,
+                b,
+                precision / 2,
+                rounding,
+            );
+            let mut scaled_denominator = imaginary_denominator.clone();
+            scaled_denominator.mul_small(n);
+            let odd = negabase_rational(
+                imaginary_negative,
+                &imaginary,
+                &scaled_denominator,
+                b,
+                precision.div_ceil(2),
+                rounding,
+            );
+
+            let top = (2 * even.integer.len()).max(2 * odd.integer.len() + 1) as i64;
+            (-(precision as i64)..top)
+                .rev()
+                .map(|place| {
+                    let half = place.div_euclid(2);
+                    if place.rem_euclid(2) == 0 {
+                        digit_at(&even, half)
+                    } else {
+                        digit_at(&odd, half)
+                    }
+                })
+                .collect()
+        }
+        ComplexBase::NegativePlusI(n) => {
+            // w = z β^precision over the common denominator of both parts
+            let scale = gaussian_pow(&base.radix(), precision);
+            let a = (real_negative, &real * &imaginary_denominator);
+            let b = (imaginary_negative, &imaginary * &real_denominator);
+            let (w_real, w_imaginary) = gaussian_mul(&(a, b), &scale);
+            let denominator = &real_denominator * &imaginary_denominator;
+            let w = (
+                round_signed(w_real, &denominator, rounding),
+                round_signed(w_imaginary, &denominator, rounding),
+            );
+            let mut digits = negative_plus_i_integer(w, n);
+            digits.reverse();
+            digits
+        }
+    };
+    let (integer, fractional) = place_point(digits, precision);
+    Number::new(integer, fractional)
+}
+
+/// Reads digits written in a complex base back to a decimal complex number
+/// with at most `precision` fraction digits in each part.
+pub fn from_complex_base(
+    number: &Number,
+    base: &ComplexBase,
+    precision: usize,
+    rounding: RoundingMode,
+) -> ComplexNumber {
+    // value = W / β^F = W conj(β)^F / |β|^2F, with W all the written digits
+    // read as an integer
+    let radix = base.radix();
+    let mut whole = (small(0), small(0));
+    for &digit in number.integer.iter().chain(&number.fractional) {
+        let (real, imaginary) = gaussian_mul(&whole, &radix);
+        whole = (add_signed(real, small(digit as i64)), imaginary);
+    }
+    let places = number.fractional.len();
+    let conjugate = (radix.0.clone(), negate(radix.1.clone()));
+    let (real, imaginary) = gaussian_mul(&whole, &gaussian_pow(&conjugate, places));
+    let denominator = BigUint::pow(base.digit_count(), places);
+
+    let part = |(negative, magnitude): Signed| {
+        let negative = (negative != number.negative) && !magnitude.is_zero();
+        convert_rational(negative, &magnitude, &denominator, 10, precision, rounding)
+    };
+    ComplexNumber {
+        real: part(real),
+        imaginary: part(imaginary),
+    }
+}
@@ -1,23 +1,75 @@
+use crate::bigint::BigUint;
+use crate::number::Number;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+/// Rounding applies to the magnitude, so `HalfUp` sends ties away from zero
+/// for negative values as well.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RoundingMode {
+    #[default]
+    TowardZero,
+    AwayFromZero,
+    HalfUp,
+    HalfEven,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct FractionalExpansion {
+    pub prefix: Vec<u32>,
+    pub repetend: Vec<u32>,
+    pub truncated: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Expansion {
+    pub negative: bool,
+    pub integer: Vec<u32>,
+    pub fractional: FractionalExpansion,
+}
+
+/// A value in normalized scientific form, `d.ddd × base^exponent`, whose
+/// leading digit is nonzero unless the value is zero.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Scientific {
+    pub negative: bool,
+    pub digits: Vec<u32>,
+    pub exponent: i64,
+}
+
 pub fn convert_integer_part(digits: &[u32], from_base: u32, to_base: u32) -> Vec<u32> {
-    if digits.is_empty() || (digits.len() == 1 && digits[0] == 0) {
+    if digits.is_empty() || digits.iter().all(|&digit| digit == 0) {
         return vec![0];
     }
 
+    if let Some(regrouping) = Regrouping::new(from_base, to_base) {
+        return regrouping.integer(digits);
+    }
+
+    if fits_in_u128(digits.len(), from_base) {
+        return convert_small_integer(digits, from_base, to_base);
+    }
+
+    BigUint::from_digits(digits, from_base).to_digits(to_base)
+}
+
+fn fits_in_u128(digit_count: usize, base: u32) -> bool {
+    let bits_per_digit = 32 - (base - 1).leading_zeros() as usize;
+    digit_count * bits_per_digit <= 127
+}
+
+fn convert_small_integer(digits: &[u32], from_base: u32, to_base: u32) -> Vec<u32> {
     let mut decimal = 0u128;
     let base = from_base as u128;
-    
+
     for &digit in digits {
         decimal = decimal * base + digit as u128;
     }
 
-    if decimal == 0 {
-        return vec![0];
-    }
-
     let mut result = Vec::new();
     let target = to_base as u128;
     let mut num = decimal;
-    
+
     while num > 0 {
         result.push((num % target) as u32);
         num /= target;
@@ -27,6 +79,119 @@ pub fn convert_integer_part(digits: &[u32], from_base: u32, to_base: u32) -> Vec
     result
 }
 
+/// Converts between bases that are powers of a common root, such as 2, 8 and
+/// 16 or 3, 9 and 27, by splitting each digit into root digits and grouping
+/// them again. This needs no arithmetic on the whole value, so it takes
+/// linear time and is exact at any length.
+#[derive(Debug, Clone, Copy)]
+struct Regrouping {
+    root: u128,
+    from_base: u128,
+    to_base: u128,
+    from_exponent: u32,
+    to_exponent: u32,
+}
+
+/// Returns the smallest `root` with `base = root^exponent`, and the exponent.
+fn perfect_power(base: u32) -> (u32, u32) {
+    for exponent in (2..=31).rev() {
+        // root < 2^(32 / exponent + 1), so search that range for an exact root
+        let (mut low, mut high) = (2u64, 1u64 << (32 / exponent + 1));
+        while low <= high {
+            let root = (low + high) / 2;
+            let power = root.checked_pow(exponent).unwrap_or(u64::MAX);
+            match power.cmp(&(base as u64)) {
+                Ordering::Equal => return (root as u32, exponent),
+                Ordering::Less => low = root + 1,
+                Ordering::Greater => high = root - 1,
+            }
+        }
+    }
+    (base, 1)
+}
+
+impl Regrouping {
+    fn new(from_base: u32, to_base: u32) -> Option<Self> {
+        let (root, from_exponent) = perfect_power(from_base);
+        let (to_root, to_exponent) = perfect_power(to_base);
+        (root == to_root).then_some(Regrouping {
+            root: root as u128,
+            from_base: from_base as u128,
+            to_base: to_base as u128,
+            from_exponent,
+            to_exponent,
+        })
+    }
+
+    /// Regroups integer digits from the least significant end.
+    fn integer(&self, digits: &[u32]) -> Vec<u32> {
+        let mut result = Vec::with_capacity(
+            digits.len() * self.from_exponent as usize / self.to_exponent as usize + 1,
+        );
+        // `pending` holds the low `count` root digits not yet written, and
+        // `weight` is root^count
+        let (mut pending, mut weight, mut count) = (0u128, 1u128, 0);
+        for &digit in digits.iter().rev() {
+            pending += digit as u128 * weight;
+            weight *= self.from_base;
+            count += self.from_exponent;
+            while count >= self.to_exponent {
+                result.push((pending % self.to_base) as u32);
+                pending /= self.to_base;
+                weight /= self.to_base;
+                count -= self.to_exponent;
+            }
+        }
+        if pending > 0 {
+            result.push(pending as u32);
+        }
+
+        while result.len() > 1 && result.last() == Some(&0) {
+            result.pop();
+        }
+        result.reverse();
+        result
+    }
+
+    /// Regroups fraction digits from the point, writing at most `limit`
+    /// digits. Also returns whether they hold the whole value, in which case
+    /// trailing zeros are dropped.
+    fn fraction(&self, digits: &[u32], limit: usize) -> (Vec<u32>, bool) {
+        let mut result = Vec::new();
+        // `pending` holds the next `count` root digits
+        let (mut pending, mut count) = (0u128, 0);
+        let mut rest = digits.iter();
+        while result.len() < limit {
+            if count < self.to_exponent {
+                match rest.next() {
+                    Some(&digit) => {
+                        pending = pending * self.from_base + digit as u128;
+                        count += self.from_exponent;
+                        continue;
+                    }
+                    None if count == 0 => break,
+                    None => {
+                        pending *= self.root.pow(self.to_exponent - count);
+                        count = self.to_exponent;
+                    }
+                }
+            }
+            count -= self.to_exponent;
+            let place = self.root.pow(count);
+            result.push((pending / place) as u32);
+            pending %= place;
+        }
+
+        let exact = pending == 0 && rest.all(|&digit| digit == 0);
+        if exact {
+            while result.last() == Some(&0) {
+                result.pop();
+            }
+        }
+        (result, exact)
+    }
+}
+
 pub fn convert_fractional_part(
     digits: &[u32],
     from_base: u32,
@@ -37,32 +202,414 @@ pub fn convert_fractional_part(
         return Vec::new();
     }
 
-    let mut decimal_fraction = 0.0;
-    let base = from_base as f64;
-    
-    for (i, &digit) in digits.iter().enumerate() {
-        decimal_fraction += digit as f64 / base.powi((i + 1) as i32);
+    if let Some(regrouping) = Regrouping::new(from_base, to_base) {
+        return regrouping.fraction(digits, precision).0;
     }
 
+    let numerator = BigUint::from_digits(digits, from_base);
+    let denominator = BigUint::pow(from_base, digits.len());
+    fraction_digits(numerator, &denominator, to_base, precision).0
+}
+
+fn fraction_digits(
+    mut numerator: BigUint,
+    denominator: &BigUint,
+    to_base: u32,
+    precision: usize,
+) -> (Vec<u32>, BigUint) {
     let mut result = Vec::new();
-    let target = to_base as f64;
-    let mut fraction = decimal_fraction;
-    
+
     for _ in 0..precision {
-        if fraction == 0.0 {
+        if numerator.is_zero() {
             break;
         }
-        
-        fraction *= target;
-        let digit = fraction.floor() as u32;
-        result.push(digit);
-        fraction -= digit as f64;
-        
-        if fraction < 1e-15 {
-            break;
+
+        numerator.mul_small(to_base);
+        let (digit, remainder) = numerator.divrem(denominator);
+        result.push(digit.to_u128().unwrap() as u32);
+        numerator = remainder;
+    }
+
+    (result, numerator)
+}
+
+pub(crate) fn rounds_up(
+    mode: RoundingMode,
+    remainder: &BigUint,
+    denominator: &BigUint,
+    last_digit: u32,
+) -> bool {
+    if remainder.is_zero() {
+        return false;
+    }
+
+    let mut twice = remainder.clone();
+    twice.mul_small(2);
+    match mode {
+        RoundingMode::TowardZero => false,
+        RoundingMode::AwayFromZero => true,
+        RoundingMode::HalfUp => twice >= *denominator,
+        RoundingMode::HalfEven => match twice.cmp(denominator) {
+            Ordering::Greater => true,
+            Ordering::Less => false,
+            Ordering::Equal => last_digit % 2 == 1,
+        },
+    }
+}
+
+pub fn expand_fractional_part(
+    digits: &[u32],
+    from_base: u32,
+    to_base: u32,
+    max_digits: usize,
+) -> FractionalExpansion {
+    if digits.is_empty() {
+        return FractionalExpansion::default();
+    }
+
+    if let Some(regrouping) = Regrouping::new(from_base, to_base) {
+        let (prefix, exact) = regrouping.fraction(digits, max_digits);
+        return FractionalExpansion {
+            prefix,
+            repetend: Vec::new(),
+            truncated: !exact,
+        };
+    }
+
+    let numerator = BigUint::from_digits(digits, from_base);
+    let denominator = BigUint::pow(from_base, digits.len());
+    expand_fraction(numerator, &denominator, to_base, max_digits)
+}
+
+fn expand_fraction(
+    mut numerator: BigUint,
+    denominator: &BigUint,
+    to_base: u32,
+    max_digits: usize,
+) -> FractionalExpansion {
+    let mut seen = HashMap::new();
+    let mut digits = Vec::new();
+
+    while !numerator.is_zero() {
+        if let Some(&start) = seen.get(&numerator) {
+            let repetend = digits.split_off(start);
+            return FractionalExpansion {
+                prefix: digits,
+                repetend,
+                truncated: false,
+            };
+        }
+        if digits.len() == max_digits {
+            return FractionalExpansion {
+                prefix: digits,
+                repetend: Vec::new(),
+                truncated: true,
+            };
         }
+
+        seen.insert(numerator.clone(), digits.len());
+        numerator.mul_small(to_base);
+        let (digit, remainder) = numerator.divrem(denominator);
+        digits.push(digit.to_u128().unwrap() as u32);
+        numerator = remainder;
     }
 
-    result
+    FractionalExpansion {
+        prefix: digits,
+        repetend: Vec::new(),
+        truncated: false,
+    }
+}
+
+pub fn convert_number(
+    number: &Number,
+    from_base: u32,
+    to_base: u32,
+    precision: usize,
+    rounding: RoundingMode,
+) -> Number {
+    let converted = match Regrouping::new(from_base, to_base) {
+        Some(regrouping) if number.repetend.is_empty() => {
+            let integer = convert_integer_part(&number.integer, from_base, to_base);
+            let (mut fractional, _) = regrouping.fraction(&number.fractional, usize::MAX);
+            let rest = fractional.split_off(precision.min(fractional.len()));
+            let remainder = BigUint::from_digits(&rest, to_base);
+            let denominator = BigUint::pow(to_base, rest.len());
+            round_digits(
+                integer,
+                fractional,
+                &remainder,
+                &denominator,
+                to_base,
+                rounding,
+            )
+        }
+        _ => {
+            let (integer, numerator, denominator) = split_value(number, from_base);
+            let integer = convert_integer_part(&integer, from_base, to_base);
+            finish_conversion(
+                integer,
+                numerator,
+                &denominator,
+                to_base,
+                precision,
+                rounding,
+            )
+        }
+    };
+    Number {
+        negative: number.negative && !number.is_zero(),
+        ..converted
+    }
+}
+
+/// Writes the signed fraction `numerator / denominator` in `to_base`, like
+/// [`convert_number`].
+pub(crate) fn convert_rational(
+    negative: bool,
+    numerator: &BigUint,
+    denominator: &BigUint,
+    to_base: u32,
+    precision: usize,
+    rounding: RoundingMode,
+) -> Number {
+    let (integer, fraction) = numerator.divrem(denominator);
+    let converted = finish_conversion(
+        integer.to_digits(to_base),
+        fraction,
+        denominator,
+        to_base,
+        precision,
+        rounding,
+    );
+    Number {
+        negative: negative && !numerator.is_zero(),
+        ..converted
+    }
+}
+
+/// Appends the fraction digits of `numerator / denominator` to the converted
+/// `integer` digits and rounds at `precision`.
+fn finish_conversion(
+    integer: Vec<u32>,
+    numerator: BigUint,
+    denominator: &BigUint,
+    to_base: u32,
+    precision: usize,
+    rounding: RoundingMode,
+) -> Number {
+    let (fractional, remainder) = fraction_digits(numerator, denominator, to_base, precision);
+    round_digits(
+        integer,
+        fractional,
+        &remainder,
+        denominator,
+        to_base,
+        rounding,
+    )
+}
+
+/// Rounds the digits written so far by the unwritten rest of the value,
+/// `remainder / denominator` of a unit in the last place.
+fn round_digits(
+    mut integer: Vec<u32>,
+    mut fractional: Vec<u32>,
+    remainder: &BigUint,
+    denominator: &BigUint,
+    to_base: u32,
+    rounding: RoundingMode,
+) -> Number {
+    let last_digit = *fractional.last().or(integer.last()).unwrap();
+    if rounds_up(rounding, remainder, denominator, last_digit) {
+        let carried = increment_digits(&fractional, to_base);
+        if carried.len() > fractional.len() {
+            integer = increment_digits(&integer, to_base);
+            fractional = carried[1..].to_vec();
+        } else {
+            fractional = carried;
+        }
+    }
+
+    Number::new(integer, fractional)
+}
+
+/// Rounds `numerator / denominator` scaled by `base^precision` to an integer.
+/// `last_digit` reads the digit that `HalfEven` keeps even off the truncated
+/// result.
+pub(crate) fn round_scaled(
+    numerator: &BigUint,
+    denominator: &BigUint,
+    base: u32,
+    precision: usize,
+    rounding: RoundingMode,
+    last_digit: impl Fn(&BigUint) -> u32,
+) -> BigUint {
+    let scaled = numerator * &BigUint::pow(base, precision);
+    let (mut magnitude, remainder) = scaled.divrem(denominator);
+    if rounds_up(rounding, &remainder, denominator, last_digit(&magnitude)) {
+        magnitude.add_small(1);
+    }
+    magnitude
+}
+
+/// Splits digits, most significant first, at `precision` fraction places,
+/// trimming leading integer zeros and trailing fraction zeros.
+pub(crate) fn place_point<T: Copy + Default + PartialEq>(
+    mut digits: Vec<T>,
+    precision: usize,
+) -> (Vec<T>, Vec<T>) {
+    let zero = T::default();
+    if digits.len() <= precision {
+        digits.splice(0..0, vec![zero; precision + 1 - digits.len()]);
+    }
+    let mut fractional = digits.split_off(digits.len() - precision);
+    while fractional.last() == Some(&zero) {
+        fractional.pop();
+    }
+    let leading_zeros = digits[..digits.len() - 1]
+        .iter()
+        .take_while(|&&digit| digit == zero)
+        .count();
+    (digits.split_off(leading_zeros), fractional)
+}
+
+pub fn expand_number(
+    number: &Number,
+    from_base: u32,
+    to_base: u32,
+    max_digits: usize,
+) -> Expansion {
+    if number.repetend.is_empty() {
+        return Expansion {
+            negative: number.negative && !number.is_zero(),
+            integer: convert_integer_part(&number.integer, from_base, to_base),
+            fractional: expand_fractional_part(&number.fractional, from_base, to_base, max_digits),
+        };
+    }
+
+    let (integer, numerator, denominator) = split_value(number, from_base);
+    Expansion {
+        negative: number.negative && !number.is_zero(),
+        integer: convert_integer_part(&integer, from_base, to_base),
+        fractional: expand_fraction(numerator, &denominator, to_base, max_digits),
+    }
+}
+
+/// Converts `number` to scientific form in `to_base`, rounding to at most
+/// `significant_digits` digits.
+pub fn convert_scientific(
+    number: &Number,
+    from_base: u32,
+    to_base: u32,
+    significant_digits: usize,
+    rounding: RoundingMode,
+) -> Scientific {
+    let (mut numerator, mut denominator) = rational_value(number, from_base);
+    if numerator.is_zero() {
+        return Scientific {
+            digits: vec![0],
+            ..Scientific::default()
+        };
+    }
+
+    // Scale the value into [1, to_base) and remember the power it took.
+    let mut exponent = 0i64;
+    let leading = numerator.divrem(&denominator).0;
+    if leading.is_zero() {
+        while numerator < denominator {
+            numerator.mul_small(to_base);
+            exponent -= 1;
+        }
+    } else {
+        let magnitude = leading.to_digits(to_base).len() - 1;
+        denominator = &denominator * &BigUint::pow(to_base, magnitude);
+        exponent = magnitude as i64;
+    }
+
+    let (first, remainder) = numerator.divrem(&denominator);
+    let mut digits = vec![first.to_u128().unwrap() as u32];
+    let (rest, remainder) = fraction_digits(
+        remainder,
+        &denominator,
+        to_base,
+        significant_digits.max(1) - 1,
+    );
+    digits.extend(rest);
+
+    if rounds_up(rounding, &remainder, &denominator, *digits.last().unwrap()) {
+        digits = increment_digits(&digits, to_base);
+        if digits.len() > significant_digits.max(1) {
+            digits.pop();
+            exponent += 1;
+        }
+    }
+    while digits.len() > 1 && digits.last() == Some(&0) {
+        digits.pop();
+    }
+
+    Scientific {
+        negative: number.negative,
+        digits,
+        exponent,
+    }
+}
+
+/// Expands `numerator / denominator` in `to_base`, detecting the repetend.
+pub(crate) fn expand_rational(
+    negative: bool,
+    numerator: &BigUint,
+    denominator: &BigUint,
+    to_base: u32,
+    max_digits: usize,
+) -> Expansion {
+    let (integer, fraction) = numerator.divrem(denominator);
+    Expansion {
+        negative: negative && !numerator.is_zero(),
+        integer: integer.to_digits(to_base),
+        fractional: expand_fraction(fraction, denominator, to_base, max_digits),
+    }
+}
+
+/// The magnitude of `number` as an exact `numerator / denominator`.
+pub(crate) fn rational_value(number: &Number, base: u32) -> (BigUint, BigUint) {
+    let (integer, numerator, denominator) = split_value(number, base);
+    let whole = &BigUint::from_digits(&integer, base) * &denominator;
+    (&whole + &numerator, denominator)
+}
+
+/// Returns the integer digits and the fraction as `numerator / denominator`,
+/// carrying into the integer when a repetend such as `0.(9)` equals one.
+fn split_value(number: &Number, base: u32) -> (Vec<u32>, BigUint, BigUint) {
+    let prefix = BigUint::from_digits(&number.fractional, base);
+    let mut denominator = BigUint::pow(base, number.fractional.len());
+    let mut numerator = prefix.clone();
+
+    if !number.repetend.is_empty() {
+        let cycle = &BigUint::pow(base, number.repetend.len()) - &BigUint::one();
+        numerator = &(&prefix * &cycle) + &BigUint::from_digits(&number.repetend, base);
+        denominator = &denominator * &cycle;
+    }
+
+    if numerator == denominator {
+        return (
+            increment_digits(&number.integer, base),
+            BigUint::zero(),
+            denominator,
+        );
+    }
+
+    (number.integer.clone(), numerator, denominator)
 }
 
+fn increment_digits(digits: &[u32], base: u32) -> Vec<u32> {
+    let mut result = digits.to_vec();
+    for digit in result.iter_mut().rev() {
+        if *digit + 1 < base {
+            *digit += 1;
+            return result;
+        }
+        *digit = 0;
+    }
+    result.insert(0, 1);
+    result
+}
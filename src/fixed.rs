@@ -0,0 +1,214 @@
+use crate::bigint::BigUint;
+use crate::complement::{MAX_BITS, SignedEncoding, decode_signed, encode_signed, pattern_width};
+use crate::converter::{Expansion, RoundingMode, expand_rational, rational_value, rounds_up};
+use crate::number::Number;
+
+/// A binary fixed-point layout. `Qm.n` is signed with `m` integer bits,
+/// counting the sign bit, and `n` fraction bits; `UQm.n` is unsigned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QFormat {
+    pub signed: bool,
+    pub integer_bits: u32,
+    pub fraction_bits: u32,
+}
+
+impl QFormat {
+    /// Reads `Q1.15`, `UQ8.8`, or the short forms `Q15` (`Q1.15`) and `UQ16`
+    /// (`UQ0.16`).
+    pub fn parse(spec: &str) -> Result<Self, String> {
+        let upper = spec.to_ascii_uppercase();
+        let (signed, rest) = match upper.strip_prefix("UQ") {
+            Some(rest) => (false, rest),
+            None => match upper.strip_prefix('Q') {
+                Some(rest) => (true, rest),
+                None => return Err(format!("Q format '{}' must start with Q or UQ", spec)),
+            },
+        };
+
+        let bits = |text: &str| {
+            text.parse::<u32>()
+                .map_err(|_| format!("Invalid Q format '{}'", spec))
+        };
+        let (integer_bits, fraction_bits) = match rest.split_once('.') {
+            Some((m, n)) => (bits(m)?, bits(n)?),
+            None => (signed as u32, bits(rest)?),
+        };
+
+        if signed && integer_bits == 0 {
+            return Err("A signed Q format needs at least 1 integer bit for the sign".to_string());
+        }
+        match integer_bits.checked_add(fraction_bits) {
+            Some(0) => return Err("A Q format needs at least 1 bit".to_string()),
+            Some(total) if total <= MAX_BITS => {}
+            _ => return Err(format!("A Q format has at most {} bits", MAX_BITS)),
+        }
+
+        Ok(QFormat {
+            signed,
+            integer_bits,
+            fraction_bits,
+        })
+    }
+
+    pub fn name(&self) -> String {
+        let prefix = if self.signed { "Q" } else { "UQ" };
+        format!("{}{}.{}", prefix, self.integer_bits, self.fraction_bits)
+    }
+
+    pub fn total_bits(&self) -> u32 {
+        self.integer_bits + self.fraction_bits
+    }
+
+    /// The largest raw magnitude on the given side of zero.
+    fn limit(&self, negative: bool) -> BigUint {
+        let bits = self.total_bits() as usize;
+        match (self.signed, negative) {
+            (true, true) => BigUint::pow(2, bits - 1),
+            (true, false) => &BigUint::pow(2, bits - 1) - &BigUint::one(),
+            (false, _) => &BigUint::pow(2, bits) - &BigUint::one(),
+        }
+    }
+}
+
+/// A value stored in a [`QFormat`] as the raw integer `±magnitude`, which
+/// stands for `±magnitude / 2^fraction_bits`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Quantized {
+    pub format: QFormat,
+    pub negative: bool,
+    pub magnitude: BigUint,
+    /// Whether the input was out of range and clamped to the nearest limit.
+    pub saturated: bool,
+}
+
+impl Quantized {
+    /// The raw integer written in `base`.
+    pub fn integer(&self, base: u32) -> Number {
+        Number {
+            negative: self.negative,
+            ..Number::new(self.magnitude.to_digits(base), Vec::new())
+        }
+    }
+
+    /// The stored word in `base`, two's complement for signed formats and
+    /// zero-padded to the full width.
+    pub fn word(&self, base: u32) -> Vec<u32> {
+        let bits = self.format.total_bits();
+        if self.format.signed {
+            return encode_signed(
+                &self.integer(base),
+                base,
+                bits,
+                SignedEncoding::TwosComplement,
+            )
+            .unwrap();
+        }
+
+        let mut digits = self.magnitude.to_digits(base);
+        let width = pattern_width(bits, base);
+        if digits.len() < width {
+            digits.splice(0..0, vec![0; width - digits.len()]);
+        }
+        digits
+    }
+
+    /// The exact represented value as a base 2 number.
+    pub fn value(&self) -> Number {
+        let digits = self.magnitude.to_digits(2);
+        let mut value =
+            Number::new(digits, Vec::new()).shifted(-(self.format.fraction_bits as i64));
+        while value.fractional.last() == Some(&0) {
+            value.fractional.pop();
+        }
+        value.negative = self.negative;
+        value
+    }
+
+    /// The represented value minus `input` (read in `base`), expanded exactly
+    /// in `to_base` up to `max_digits` fraction digits.
+    pub fn error(&self, input: &Number, base: u32, to_base: u32, max_digits: usize) -> Expansion {
+        let (numerator, denominator) = rational_value(input, base);
+        let stored = &self.magnitude * &denominator;
+        let original = &numerator * &BigUint::pow(2, self.format.fraction_bits as usize);
+        let original_negative = input.negative && !numerator.is_zero();
+
+        let (negative, difference) = if self.negative != original_negative {
+            (self.negative, &stored + &original)
+        } else if stored >= original {
+            (self.negative, &stored - &original)
+        } else {
+            (!self.negative, &original - &stored)
+        };
+        let scale = &denominator * &BigUint::pow(2, self.format.fraction_bits as usize);
+        expand_rational(negative, &difference, &scale, to_base, max_digits)
+    }
+}
+
+/// Scales `number` (read in `base`) by `2^fraction_bits`, rounds it to an
+/// integer and saturates it to the range of `format`.
+pub fn quantize(number: &Number, base: u32, format: QFormat, rounding: RoundingMode) -> Quantized {
+    let (numerator, denominator) = rational_value(number, base);
+    let scaled = &numerator * &BigUint::pow(2, format.fraction_bits as usize);
+    let (mut magnitude, remainder) = scaled.divrem(&denominator);
+    let last_bit = magnitude.clone().divrem_small(2);
+    if rounds_up(rounding, &remainder, &denominator, last_bit) {
+        magnitude.add_small(1);
+    }
+
+    let negative = number.negative && !magnitude.is_zero();
+    if negative && !format.signed {
+        return Quantized {
+            format,
+            negative: false,
+            magnitude: BigUint::zero(),
+            saturated: true,
+        };
+    }
+
+    let limit = format.limit(negative);
+    let saturated = magnitude > limit;
+    Quantized {
+        format,
+        negative,
+        magnitude: if saturated { limit } else { magnitude },
+        saturated,
+    }
+}
+
+/// Reads a stored word back. A non-negative integer is the bit pattern, two's
+/// complement for signed formats; a negative integer is the raw value itself.
+pub fn dequantize(number: &Number, base: u32, format: QFormat) -> Result<Quantized, String> {
+    let magnitude = number.integer_value(base)?;
+    let negative = number.negative && !magnitude.is_zero();
+    let (negative, magnitude) = if negative || !format.signed {
+        if negative && !format.signed {
+            return Err(format!("{} cannot hold a negative value", format.name()));
+        }
+        if magnitude > format.limit(negative) {
+            return Err(format!(
+                "Overflow: value is outside the {}-bit {} range",
+                format.total_bits(),
+                format.name()
+            ));
+        }
+        (negative, magnitude)
+    } else {
+        let decoded = decode_signed(
+            &number.integer,
+            base,
+            format.total_bits(),
+            SignedEncoding::TwosComplement,
+        )?;
+        (
+            decoded.negative,
+            BigUint::from_digits(&decoded.integer, base),
+        )
+    };
+
+    Ok(Quantized {
+        format,
+        negative,
+        magnitude,
+        saturated: false,
+    })
+}
@@ -1,29 +1,180 @@
-fn digit_to_char(digit: u32) -> char {
-    if digit < 10 {
-        (digit as u8 + b'0') as char
-    } else {
-        ((digit - 10) as u8 + b'A') as char
+use crate::alphabet::Alphabet;
+use crate::converter::{Expansion, FractionalExpansion, Scientific};
+use crate::number::Number;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RepeatNotation {
+    #[default]
+    Parentheses,
+    Overline,
+}
+
+/// Splits digits into groups of `size`, counted outward from the radix point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DigitGrouping {
+    pub size: usize,
+    pub separator: char,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FormatOptions {
+    pub alphabet: Alphabet,
+    pub notation: RepeatNotation,
+    /// Writes letters in lowercase. Ignored for case-sensitive alphabets,
+    /// where changing case would change the digit.
+    pub lowercase: bool,
+    pub grouping: Option<DigitGrouping>,
+}
+
+impl FormatOptions {
+    fn symbol(&self, digit: u32) -> char {
+        let symbol = self.alphabet.symbol(digit);
+        if self.lowercase && !self.alphabet.is_case_sensitive() {
+            symbol.to_lowercase().next().unwrap_or(symbol)
+        } else {
+            symbol
+        }
     }
 }
 
-pub fn format_result(integer_digits: &[u32], fractional_digits: &[u32]) -> String {
+fn digit_cells(digits: &[u32], options: &FormatOptions) -> Vec<String> {
+    digits
+        .iter()
+        .map(|&digit| options.symbol(digit).to_string())
+        .collect()
+}
+
+/// Joins rendered digits into groups counted from the right end when
+/// `align_right` is set (integer parts) and from the left otherwise.
+fn join_groups(cells: &[String], grouping: Option<DigitGrouping>, align_right: bool) -> String {
     let mut result = String::new();
-    
-    if integer_digits.is_empty() {
-        result.push('0');
+    for (i, cell) in cells.iter().enumerate() {
+        if let Some(grouping) = grouping
+            && grouping.size > 0
+            && i > 0
+        {
+            let position = if align_right { cells.len() - i } else { i };
+            if position % grouping.size == 0 {
+                result.push(grouping.separator);
+            }
+        }
+        result.push_str(cell);
+    }
+    result
+}
+
+fn format_unsigned(
+    integer_digits: &[u32],
+    expansion: &FractionalExpansion,
+    options: &FormatOptions,
+) -> String {
+    let integer = if integer_digits.is_empty() {
+        vec![options.symbol(0).to_string()]
     } else {
-        for &digit in integer_digits {
-            result.push(digit_to_char(digit));
+        digit_cells(integer_digits, options)
+    };
+    let mut result = join_groups(&integer, options.grouping, true);
+
+    let mut fraction = digit_cells(&expansion.prefix, options);
+    if !expansion.repetend.is_empty() {
+        let mut repetend = digit_cells(&expansion.repetend, options);
+        match options.notation {
+            RepeatNotation::Parentheses => {
+                repetend[0].insert(0, '(');
+                repetend.last_mut().unwrap().push(')');
+            }
+            RepeatNotation::Overline => {
+                for cell in &mut repetend {
+                    cell.push('\u{0305}');
+                }
+            }
         }
+        fraction.extend(repetend);
     }
-    
-    if !fractional_digits.is_empty() {
+
+    if !fraction.is_empty() {
         result.push('.');
-        for &digit in fractional_digits {
-            result.push(digit_to_char(digit));
-        }
+        result.push_str(&join_groups(&fraction, options.grouping, false));
     }
-    
+    if expansion.truncated && expansion.repetend.is_empty() {
+        result.push_str("...");
+    }
+
     result
 }
 
+pub fn format_result(integer_digits: &[u32], fractional_digits: &[u32]) -> String {
+    format_result_with(integer_digits, fractional_digits, &FormatOptions::default())
+}
+
+/// Like [`format_result`], with the digits written according to `options`.
+pub fn format_result_with(
+    integer_digits: &[u32],
+    fractional_digits: &[u32],
+    options: &FormatOptions,
+) -> String {
+    let expansion = FractionalExpansion {
+        prefix: fractional_digits.to_vec(),
+        ..FractionalExpansion::default()
+    };
+    format_unsigned(integer_digits, &expansion, options)
+}
+
+pub fn format_repeating(
+    integer_digits: &[u32],
+    expansion: &FractionalExpansion,
+    notation: RepeatNotation,
+) -> String {
+    let options = FormatOptions {
+        notation,
+        ..FormatOptions::default()
+    };
+    format_unsigned(integer_digits, expansion, &options)
+}
+
+pub fn format_number(number: &Number) -> String {
+    format_number_with(number, &FormatOptions::default())
+}
+
+pub fn format_number_with(number: &Number, options: &FormatOptions) -> String {
+    let expansion = Expansion {
+        negative: number.negative,
+        integer: number.integer.clone(),
+        fractional: FractionalExpansion {
+            prefix: number.fractional.clone(),
+            repetend: number.repetend.clone(),
+            truncated: false,
+        },
+    };
+    format_expansion_with(&expansion, options)
+}
+
+pub fn format_expansion(expansion: &Expansion, notation: RepeatNotation) -> String {
+    let options = FormatOptions {
+        notation,
+        ..FormatOptions::default()
+    };
+    format_expansion_with(expansion, &options)
+}
+
+pub fn format_expansion_with(expansion: &Expansion, options: &FormatOptions) -> String {
+    let digits = format_unsigned(&expansion.integer, &expansion.fractional, options);
+    if expansion.negative {
+        format!("-{}", digits)
+    } else {
+        digits
+    }
+}
+
+/// Writes `scientific` as `d.ddd` followed by its exponent in decimal: `e` for
+/// powers of ten in base 10 and `@` for powers of `base` otherwise.
+pub fn format_scientific(scientific: &Scientific, base: u32, options: &FormatOptions) -> String {
+    let expansion = FractionalExpansion {
+        prefix: scientific.digits[1..].to_vec(),
+        ..FractionalExpansion::default()
+    };
+    let mantissa = format_unsigned(&scientific.digits[..1], &expansion, options);
+    let marker = if base == 10 { 'e' } else { '@' };
+    let sign = if scientific.negative { "-" } else { "" };
+    format!("{}{}{}{}", sign, mantissa, marker, scientific.exponent)
+}
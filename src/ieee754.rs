@@ -0,0 +1,328 @@
+use crate::bigint::BigUint;
+use crate::converter::{
+    RoundingMode, convert_fractional_part, convert_integer_part, rational_value, rounds_up,
+};
+use crate::formatter::format_result;
+use crate::number::Number;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FloatFormat {
+    Half,
+    BFloat16,
+    Single,
+    Double,
+    Quad,
+}
+
+impl FloatFormat {
+    pub fn name(self) -> &'static str {
+        match self {
+            FloatFormat::Half => "binary16",
+            FloatFormat::BFloat16 => "bfloat16",
+            FloatFormat::Single => "binary32",
+            FloatFormat::Double => "binary64",
+            FloatFormat::Quad => "binary128",
+        }
+    }
+
+    pub fn exponent_bits(self) -> u32 {
+        match self {
+            FloatFormat::Half => 5,
+            FloatFormat::BFloat16 | FloatFormat::Single => 8,
+            FloatFormat::Double => 11,
+            FloatFormat::Quad => 15,
+        }
+    }
+
+    /// Stored mantissa bits, not counting the implicit leading bit.
+    pub fn fraction_bits(self) -> u32 {
+        match self {
+            FloatFormat::Half => 10,
+            FloatFormat::BFloat16 => 7,
+            FloatFormat::Single => 23,
+            FloatFormat::Double => 52,
+            FloatFormat::Quad => 112,
+        }
+    }
+
+    pub fn total_bits(self) -> u32 {
+        1 + self.exponent_bits() + self.fraction_bits()
+    }
+
+    pub fn bias(self) -> i64 {
+        (1 << (self.exponent_bits() - 1)) - 1
+    }
+
+    fn max_biased_exponent(self) -> u32 {
+        (1 << self.exponent_bits()) - 1
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FloatClass {
+    Zero,
+    Subnormal,
+    Normal,
+    Infinite,
+    QuietNan,
+    SignalingNan,
+}
+
+/// The three fields of an IEEE 754 binary interchange encoding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FloatBits {
+    pub format: FloatFormat,
+    pub sign: bool,
+    /// The biased exponent field.
+    pub exponent: u32,
+    pub fraction: u128,
+}
+
+impl FloatBits {
+    pub fn from_bits(format: FloatFormat, bits: u128) -> Result<Self, String> {
+        let total = format.total_bits();
+        if total < 128 && bits >> total != 0 {
+            return Err(format!(
+                "Pattern does not fit in the {} bits of {}",
+                total,
+                format.name()
+            ));
+        }
+
+        let fraction_bits = format.fraction_bits();
+        Ok(FloatBits {
+            format,
+            sign: (bits >> (total - 1)) & 1 == 1,
+            exponent: ((bits >> fraction_bits) as u32) & format.max_biased_exponent(),
+            fraction: bits & ((1 << fraction_bits) - 1),
+        })
+    }
+
+    pub fn to_bits(&self) -> u128 {
+        let fraction_bits = self.format.fraction_bits();
+        ((self.sign as u128) << (self.format.total_bits() - 1))
+            | ((self.exponent as u128) << fraction_bits)
+            | self.fraction
+    }
+
+    pub fn zero(format: FloatFormat, negative: bool) -> Self {
+        FloatBits {
+            format,
+            sign: negative,
+            exponent: 0,
+            fraction: 0,
+        }
+    }
+
+    pub fn infinity(format: FloatFormat, negative: bool) -> Self {
+        FloatBits {
+            format,
+            sign: negative,
+            exponent: format.max_biased_exponent(),
+            fraction: 0,
+        }
+    }
+
+    /// A NaN carrying `payload` in the fraction bits below the quiet bit.
+    pub fn nan(
+        format: FloatFormat,
+        negative: bool,
+        quiet: bool,
+        payload: u128,
+    ) -> Result<Self, String> {
+        let quiet_bit = 1u128 << (format.fraction_bits() - 1);
+        if payload >= quiet_bit {
+            return Err(format!(
+                "NaN payload does not fit in the {} payload bits of {}",
+                format.fraction_bits() - 1,
+                format.name()
+            ));
+        }
+        if !quiet && payload == 0 {
+            return Err("A signaling NaN needs a nonzero payload".to_string());
+        }
+
+        Ok(FloatBits {
+            format,
+            sign: negative,
+            exponent: format.max_biased_exponent(),
+            fraction: if quiet { quiet_bit | payload } else { payload },
+        })
+    }
+
+    pub fn classify(&self) -> FloatClass {
+        let quiet_bit = 1u128 << (self.format.fraction_bits() - 1);
+        match (self.exponent, self.fraction) {
+            (0, 0) => FloatClass::Zero,
+            (0, _) => FloatClass::Subnormal,
+            (e, 0) if e == self.format.max_biased_exponent() => FloatClass::Infinite,
+            (e, f) if e == self.format.max_biased_exponent() && f & quiet_bit != 0 => {
+                FloatClass::QuietNan
+            }
+            (e, _) if e == self.format.max_biased_exponent() => FloatClass::SignalingNan,
+            _ => FloatClass::Normal,
+        }
+    }
+
+    /// The payload of a NaN, without its quiet bit.
+    pub fn nan_payload(&self) -> Option<u128> {
+        match self.classify() {
+            FloatClass::QuietNan | FloatClass::SignalingNan => {
+                Some(self.fraction & ((1 << (self.format.fraction_bits() - 1)) - 1))
+            }
+            _ => None,
+        }
+    }
+
+    /// The exponent the significand is scaled by, with the bias removed.
+    /// Subnormals share the smallest normal exponent.
+    pub fn unbiased_exponent(&self) -> i64 {
+        self.exponent.max(1) as i64 - self.format.bias()
+    }
+
+    /// The exact value as a base 2 number, or `None` for infinities and NaNs.
+    pub fn value(&self) -> Option<Number> {
+        let significand = match self.classify() {
+            FloatClass::Infinite | FloatClass::QuietNan | FloatClass::SignalingNan => return None,
+            FloatClass::Zero | FloatClass::Subnormal => self.fraction,
+            FloatClass::Normal => self.fraction | (1 << self.format.fraction_bits()),
+        };
+
+        let digits = BigUint::from_u128(significand).to_digits(2);
+        let shift = self.unbiased_exponent() - self.format.fraction_bits() as i64;
+        let mut value = Number::new(digits, Vec::new()).shifted(shift);
+        while value.fractional.last() == Some(&0) {
+            value.fractional.pop();
+        }
+        value.negative = self.sign;
+        Some(value)
+    }
+}
+
+/// `numerator / denominator` multiplied by `2^shift`, kept as a fraction.
+fn scale_by_power_of_two(
+    numerator: BigUint,
+    denominator: BigUint,
+    shift: i64,
+) -> (BigUint, BigUint) {
+    let factor = BigUint::pow(2, shift.unsigned_abs() as usize);
+    if shift >= 0 {
+        (&numerator * &factor, denominator)
+    } else {
+        (numerator, &denominator * &factor)
+    }
+}
+
+/// Rounds `number`, read in `base`, to the nearest `format` value with ties
+/// to even, overflowing to infinity and underflowing through subnormals.
+pub fn encode_float(number: &Number, base: u32, format: FloatFormat) -> FloatBits {
+    let (numerator, denominator) = rational_value(number, base);
+    let sign = number.negative;
+    if numerator.is_zero() {
+        return FloatBits::zero(format, sign);
+    }
+
+    let precision = format.fraction_bits() as i64 + 1;
+    let min_exponent = 1 - format.bias();
+    let max_exponent = format.bias();
+
+    let mut exponent = numerator.bits() as i64 - denominator.bits() as i64;
+    let (scaled, unit) = scale_by_power_of_two(numerator.clone(), denominator.clone(), -exponent);
+    if scaled < unit {
+        exponent -= 1;
+    }
+    if exponent > max_exponent {
+        return FloatBits::infinity(format, sign);
+    }
+    if exponent < min_exponent - precision {
+        return FloatBits::zero(format, sign);
+    }
+
+    let mut exponent = exponent.max(min_exponent);
+    let (scaled, unit) = scale_by_power_of_two(numerator, denominator, precision - 1 - exponent);
+    let (significand, remainder) = scaled.divrem(&unit);
+    let mut significand = significand.to_u128().unwrap();
+    if rounds_up(
+        RoundingMode::HalfEven,
+        &remainder,
+        &unit,
+        (significand & 1) as u32,
+    ) {
+        significand += 1;
+    }
+
+    if significand == 1 << precision {
+        significand >>= 1;
+        exponent += 1;
+    }
+    if exponent > max_exponent {
+        return FloatBits::infinity(format, sign);
+    }
+
+    let implicit_bit = 1u128 << (precision - 1);
+    FloatBits {
+        format,
+        sign,
+        exponent: if significand >= implicit_bit {
+            (exponent + format.bias()) as u32
+        } else {
+            0
+        },
+        fraction: significand & (implicit_bit - 1),
+    }
+}
+
+/// Reads `inf`, `infinity`, `nan`, `snan` and `nan(PAYLOAD)` with an optional
+/// sign, where the payload is decimal or `0x` hex. Other text gives `None`.
+pub fn special_float(text: &str, format: FloatFormat) -> Result<Option<FloatBits>, String> {
+    let lower = text.to_ascii_lowercase();
+    let (negative, name) = match lower.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, lower.strip_prefix('+').unwrap_or(&lower)),
+    };
+
+    if name == "inf" || name == "infinity" {
+        return Ok(Some(FloatBits::infinity(format, negative)));
+    }
+
+    let (quiet, rest) = match name.strip_prefix("snan") {
+        Some(rest) => (false, rest),
+        None => match name.strip_prefix("nan") {
+            Some(rest) => (true, rest),
+            None => return Ok(None),
+        },
+    };
+    let payload = match rest {
+        "" => 0,
+        _ => {
+            let Some(payload) = rest.strip_prefix('(').and_then(|r| r.strip_suffix(')')) else {
+                return Ok(None);
+            };
+            let parsed = match payload.strip_prefix("0x") {
+                Some(hex) => u128::from_str_radix(hex, 16),
+                None => payload.parse::<u128>(),
+            };
+            parsed.map_err(|_| format!("Invalid NaN payload '{}'", payload))?
+        }
+    };
+    let payload = if !quiet && rest.is_empty() {
+        1
+    } else {
+        payload
+    };
+
+    FloatBits::nan(format, negative, quiet, payload).map(Some)
+}
+
+/// Writes a base 2 value as its exact decimal expansion. A binary fraction of
+/// `n` digits has exactly `n` decimal digits, so nothing is rounded.
+pub fn exact_decimal(value: &Number) -> String {
+    let integer = convert_integer_part(&value.integer, 2, 10);
+    let fractional = convert_fractional_part(&value.fractional, 2, 10, value.fractional.len());
+    let digits = format_result(&integer, &fractional);
+    if value.negative {
+        format!("-{}", digits)
+    } else {
+        digits
+    }
+}
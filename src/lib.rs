@@ -1,7 +1,64 @@
+pub mod alphabet;
+pub mod balanced;
+pub mod beta;
+pub mod bigint;
+pub mod bijective;
+pub mod codes;
+pub mod complement;
+pub mod complex;
 pub mod converter;
+pub mod fixed;
 pub mod formatter;
+pub mod ieee754;
+pub mod mixed;
+pub mod negabase;
+pub mod number;
 pub mod parser;
+pub mod phinary;
+pub mod roman;
+pub mod zeckendorf;
 
-pub use converter::{convert_fractional_part, convert_integer_part};
-pub use formatter::format_result;
-pub use parser::parse_number;
\ No newline at end of file
+pub use alphabet::Alphabet;
+pub use balanced::{
+    SignedDigits, balanced_alphabet, check_balanced_base, format_balanced, from_balanced,
+    parse_balanced, to_balanced,
+};
+pub use beta::{
+    APPROXIMATION_DIGITS, BetaExpansion, QuadraticRadix, RealRadix, beta_expand,
+    beta_to_positional, beta_value, convert_from_beta, expand_from_beta, parse_beta,
+};
+pub use bigint::BigUint;
+pub use bijective::{
+    Bijective, bijective_alphabet, check_bijective_base, format_bijective, parse_bijective,
+};
+pub use codes::{BinaryCode, decode_binary_code, encode_binary_code};
+pub use complement::{SignedEncoding, decode_signed, encode_signed};
+pub use complex::{
+    ComplexBase, ComplexNumber, format_complex, from_complex_base, parse_complex,
+    parse_complex_base, to_complex_base,
+};
+pub use converter::{
+    Expansion, FractionalExpansion, RoundingMode, Scientific, convert_fractional_part,
+    convert_integer_part, convert_number, convert_scientific, expand_fractional_part,
+    expand_number,
+};
+pub use fixed::{QFormat, Quantized, dequantize, quantize};
+pub use formatter::{
+    DigitGrouping, FormatOptions, RepeatNotation, format_expansion, format_expansion_with,
+    format_number, format_number_with, format_repeating, format_result, format_result_with,
+    format_scientific,
+};
+pub use ieee754::{FloatBits, FloatClass, FloatFormat, encode_float, exact_decimal, special_float};
+pub use mixed::{
+    MixedDigits, MixedRadix, convert_from_mixed_radix, convert_to_mixed_radix, format_mixed_radix,
+    parse_mixed_radix,
+};
+pub use negabase::{convert_signed_radix, expand_signed_radix};
+pub use number::Number;
+pub use parser::{
+    detect_radix, parse_literal, parse_number, parse_number_full, parse_number_with,
+    parse_signed_radix,
+};
+pub use phinary::{from_phinary, to_phinary};
+pub use roman::{MAX_ROMAN, from_roman, to_roman};
+pub use zeckendorf::{fibonacci_decode, fibonacci_encode, from_zeckendorf, to_zeckendorf};
@@ -1,67 +1,1352 @@
-use base_converter::{convert_fractional_part, convert_integer_part, format_result, parse_number};
-use clap::Parser;
-use std::io::{self, Write};
+use base_converter::{
+    APPROXIMATION_DIGITS, Alphabet, BigUint, Bijective, BinaryCode, ComplexBase, ComplexNumber,
+    DigitGrouping, FloatBits, FloatClass, FloatFormat, FormatOptions, MixedRadix, Number, QFormat,
+    RealRadix, RepeatNotation, RoundingMode, SignedEncoding, balanced_alphabet, beta_expand,
+    beta_to_positional, bijective_alphabet, check_balanced_base, check_bijective_base,
+    convert_from_mixed_radix, convert_number, convert_scientific, convert_signed_radix,
+    convert_to_mixed_radix, decode_binary_code, decode_signed, dequantize, detect_radix,
+    encode_binary_code, encode_float, encode_signed, exact_decimal, expand_number,
+    expand_signed_radix, fibonacci_decode, fibonacci_encode, format_balanced, format_bijective,
+    format_complex, format_expansion_with, format_mixed_radix, format_number, format_number_with,
+    format_scientific, from_balanced, from_complex_base, from_phinary, from_roman, from_zeckendorf,
+    parse_balanced, parse_beta, parse_bijective, parse_complex, parse_complex_base,
+    parse_mixed_radix, parse_number_full, parse_number_with, parse_signed_radix, quantize,
+    special_float, to_balanced, to_complex_base, to_phinary, to_roman, to_zeckendorf,
+};
+use clap::{ArgGroup, Parser, ValueEnum};
+use std::io::{self, Read, Write};
 
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
 #[command(about = "Convert numbers between different base systems (supports fractional values)")]
+#[command(group(
+    ArgGroup::new("from_numeration")
+        .args(["from_balanced", "from_bijective", "from_radix", "from_system", "from_code", "from_beta"])
+        .multiple(false)
+        .conflicts_with_all(["interactive", "float", "qformat", "signed_input"])
+))]
+#[command(group(
+    ArgGroup::new("to_numeration")
+        .args(["to_balanced", "bijective", "to_radix", "to_system", "code", "to_beta"])
+        .multiple(false)
+        .conflicts_with_all(["interactive", "float", "qformat", "bits", "scientific", "repeat"])
+))]
+#[command(group(
+    ArgGroup::new("complex")
+        .args(["from_complex", "to_complex"])
+        .multiple(true)
+        .conflicts_with_all([
+            "interactive", "float", "qformat", "signed_input", "bits", "scientific", "repeat",
+            "from_numeration", "to_numeration",
+        ])
+))]
 struct Args {
-    #[arg(short, long, conflicts_with = "interactive")]
+    #[arg(
+        short,
+        long,
+        conflicts_with = "interactive",
+        allow_hyphen_values = true,
+        help = "Number to convert, or - to read it from stdin"
+    )]
     value: Option<String>,
 
-    #[arg(short = 's', long, conflicts_with = "interactive")]
-    from_base: Option<u32>,
+    #[arg(
+        short = 's',
+        long,
+        conflicts_with = "interactive",
+        allow_negative_numbers = true,
+        help = "Source base, negative for negabases; optional when the value has a radix prefix or suffix"
+    )]
+    from_base: Option<i32>,
 
-    #[arg(short = 't', long, conflicts_with = "interactive")]
-    to_base: Option<u32>,
+    #[arg(
+        short = 't',
+        long,
+        conflicts_with = "interactive",
+        allow_negative_numbers = true,
+        help = "Target base, negative for negabases such as -2"
+    )]
+    to_base: Option<i32>,
 
     #[arg(short = 'p', long, default_value_t = 10)]
     precision: usize,
 
     #[arg(short, long, help = "Run in interactive mode")]
     interactive: bool,
+
+    #[arg(
+        short = 'r',
+        long,
+        value_enum,
+        help = "Detect repeating fractions and mark the repetend"
+    )]
+    repeat: Option<RepeatStyle>,
+
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = Rounding::TowardZero,
+        help = "How to round fractions cut off at the precision limit"
+    )]
+    rounding: Rounding,
+
+    #[arg(long, help = "Show the result as a fixed-width signed bit pattern")]
+    bits: Option<u32>,
+
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = Encoding::Twos,
+        help = "Signed encoding used with --bits"
+    )]
+    encoding: Encoding,
+
+    #[arg(
+        long,
+        requires = "bits",
+        help = "Interpret the input as a --bits wide signed pattern"
+    )]
+    signed_input: bool,
+
+    #[arg(
+        short = 'a',
+        long,
+        help = "Digit alphabet for both sides: base58, base62, base64 or the symbols themselves"
+    )]
+    alphabet: Option<String>,
+
+    #[arg(long, help = "Digit alphabet for the input, overriding --alphabet")]
+    from_alphabet: Option<String>,
+
+    #[arg(long, help = "Digit alphabet for the result, overriding --alphabet")]
+    to_alphabet: Option<String>,
+
+    #[arg(short = 'l', long, help = "Write letter digits in lowercase")]
+    lowercase: bool,
+
+    #[arg(
+        long,
+        conflicts_with = "ignore_case",
+        help = "Reject input digits whose case differs from the alphabet"
+    )]
+    case_sensitive: bool,
+
+    #[arg(long, help = "Fold case when reading input digits")]
+    ignore_case: bool,
+
+    #[arg(
+        short = 'g',
+        long,
+        help = "Group result digits in blocks of N, counted from the radix point"
+    )]
+    group: Option<usize>,
+
+    #[arg(
+        long,
+        default_value_t = '_',
+        requires = "group",
+        help = "Character placed between digit groups"
+    )]
+    group_separator: char,
+
+    #[arg(
+        long,
+        value_name = "DIGITS",
+        conflicts_with_all = ["repeat", "bits"],
+        help = "Write the result in scientific notation with this many significant digits"
+    )]
+    scientific: Option<usize>,
+
+    #[arg(
+        long,
+        value_enum,
+        conflicts_with = "interactive",
+        help = "Show the IEEE 754 encoding of the value in this format"
+    )]
+    float: Option<Float>,
+
+    #[arg(
+        long,
+        requires = "float",
+        help = "Read the value as a raw --float bit pattern and decode it"
+    )]
+    from_bits: bool,
+
+    #[arg(
+        long,
+        value_name = "FORMAT",
+        conflicts_with_all = ["interactive", "float"],
+        help = "Convert to a fixed-point Q format such as Q1.15, Q15 or UQ8.8"
+    )]
+    qformat: Option<String>,
+
+    #[arg(
+        long,
+        requires = "qformat",
+        help = "Read the value as a stored --qformat word and show its real value"
+    )]
+    from_word: bool,
+
+    #[arg(
+        long,
+        requires = "from_base",
+        help = "Read the value in the balanced form of the odd --from-base, such as balanced ternary T01"
+    )]
+    from_balanced: bool,
+
+    #[arg(
+        long,
+        help = "Write the result in the balanced form of the odd --to-base, with digits from -(b-1)/2 to (b-1)/2"
+    )]
+    to_balanced: bool,
+
+    #[arg(
+        long,
+        requires = "from_base",
+        help = "Read the value in bijective --from-base, with digits 1 to k and no zero (base 26 is A-Z)"
+    )]
+    from_bijective: bool,
+
+    #[arg(
+        long,
+        help = "Write the result in bijective --to-base, such as spreadsheet columns in base 26"
+    )]
+    bijective: bool,
+
+    #[arg(
+        long,
+        value_name = "SPEC",
+        conflicts_with = "from_base",
+        help = "Read the value as colon-separated mixed-radix digits, e.g. 24:60:60, time or factoradic"
+    )]
+    from_radix: Option<String>,
+
+    #[arg(
+        long,
+        value_name = "SPEC",
+        conflicts_with = "to_base",
+        help = "Write the result in mixed radix, e.g. 24:60:60 for days:hours:minutes:seconds, time or factoradic"
+    )]
+    to_radix: Option<String>,
+
+    #[arg(
+        long,
+        visible_alias = "from",
+        value_enum,
+        conflicts_with = "from_base",
+        help = "Read the value in a non-positional system such as roman instead of --from-base"
+    )]
+    from_system: Option<System>,
+
+    #[arg(
+        long,
+        visible_alias = "to",
+        value_enum,
+        conflicts_with = "to_base",
+        help = "Write the result in a non-positional system such as roman instead of --to-base"
+    )]
+    to_system: Option<System>,
+
+    #[arg(
+        long,
+        value_enum,
+        conflicts_with = "from_base",
+        help = "Read the value as bits in a binary code such as Gray code or BCD instead of --from-base"
+    )]
+    from_code: Option<Code>,
+
+    #[arg(
+        long,
+        value_enum,
+        conflicts_with = "to_base",
+        help = "Write the result as bits in a binary code such as Gray code or BCD instead of --to-base"
+    )]
+    code: Option<Code>,
+
+    #[arg(
+        long,
+        value_name = "RADIX",
+        conflicts_with = "from_base",
+        help = "Read the value in a non-integer radix greater than 1, such as 3/2, 1.5, e, pi, phi or sqrt2"
+    )]
+    from_beta: Option<String>,
+
+    #[arg(
+        long,
+        value_name = "RADIX",
+        conflicts_with = "to_base",
+        help = "Write the greedy expansion in a non-integer radix such as 3/2, e or sqrt2, up to --precision fraction digits"
+    )]
+    to_beta: Option<String>,
+
+    #[arg(
+        long,
+        value_name = "BASE",
+        allow_hyphen_values = true,
+        help = "Read the value in the complex base -n+i or ni, such as -1+i or 2i (quater-imaginary); the result is a decimal complex number"
+    )]
+    from_complex: Option<String>,
+
+    #[arg(
+        long,
+        help = "Accept non-canonical Roman numerals such as IIII or VIIII with --from roman"
+    )]
+    lenient_roman: bool,
+
+    #[arg(
+        long,
+        value_name = "BASE",
+        allow_hyphen_values = true,
+        help = "Write a decimal complex value such as 3+4i in the complex base -n+i or ni, such as -1+i or 2i"
+    )]
+    to_complex: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum RepeatStyle {
+    Parens,
+    Overline,
+}
+
+impl From<RepeatStyle> for RepeatNotation {
+    fn from(style: RepeatStyle) -> Self {
+        match style {
+            RepeatStyle::Parens => RepeatNotation::Parentheses,
+            RepeatStyle::Overline => RepeatNotation::Overline,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum Rounding {
+    TowardZero,
+    AwayFromZero,
+    HalfUp,
+    HalfEven,
+}
+
+impl From<Rounding> for RoundingMode {
+    fn from(rounding: Rounding) -> Self {
+        match rounding {
+            Rounding::TowardZero => RoundingMode::TowardZero,
+            Rounding::AwayFromZero => RoundingMode::AwayFromZero,
+            Rounding::HalfUp => RoundingMode::HalfUp,
+            Rounding::HalfEven => RoundingMode::HalfEven,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum Encoding {
+    Twos,
+    Ones,
+    SignMagnitude,
+}
+
+impl From<Encoding> for SignedEncoding {
+    fn from(encoding: Encoding) -> Self {
+        match encoding {
+            Encoding::Twos => SignedEncoding::TwosComplement,
+            Encoding::Ones => SignedEncoding::OnesComplement,
+            Encoding::SignMagnitude => SignedEncoding::SignMagnitude,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum Float {
+    F16,
+    Bf16,
+    F32,
+    F64,
+    F128,
+}
+
+impl From<Float> for FloatFormat {
+    fn from(float: Float) -> Self {
+        match float {
+            Float::F16 => FloatFormat::Half,
+            Float::Bf16 => FloatFormat::BFloat16,
+            Float::F32 => FloatFormat::Single,
+            Float::F64 => FloatFormat::Double,
+            Float::F128 => FloatFormat::Quad,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum System {
+    /// Sums of non-consecutive Fibonacci numbers
+    Zeckendorf,
+    /// Zeckendorf digits reversed and terminated by 11
+    Fibonacci,
+    /// Base φ, the golden ratio, in standard form
+    Phinary,
+    /// Roman numerals, with a vinculum (overline) for thousands from 4000
+    Roman,
+}
+
+impl From<System> for Numeration {
+    fn from(system: System) -> Self {
+        match system {
+            System::Zeckendorf => Numeration::Zeckendorf,
+            System::Fibonacci => Numeration::FibonacciCode,
+            System::Phinary => Numeration::Phinary,
+            System::Roman => Numeration::Roman,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum Code {
+    /// Reflected binary Gray code
+    Gray,
+    /// Packed BCD (8421), a nibble per decimal digit
+    #[value(alias = "8421")]
+    Bcd,
+    /// Unpacked BCD, a byte per decimal digit
+    UnpackedBcd,
+    /// Excess-3, each decimal digit plus 3
+    Excess3,
+    /// 2-of-5 with weights 7-4-2-1-0
+    TwoOfFive,
+    /// Aiken 2421, self-complementing
+    #[value(name = "2421")]
+    Aiken,
+}
+
+impl From<Code> for BinaryCode {
+    fn from(code: Code) -> Self {
+        match code {
+            Code::Gray => BinaryCode::Gray,
+            Code::Bcd => BinaryCode::PackedBcd,
+            Code::UnpackedBcd => BinaryCode::UnpackedBcd,
+            Code::Excess3 => BinaryCode::Excess3,
+            Code::TwoOfFive => BinaryCode::TwoOfFive,
+            Code::Aiken => BinaryCode::Aiken2421,
+        }
+    }
+}
+
+/// How the digits on one side of a conversion are read or written.
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Numeration {
+    Positional,
+    Balanced,
+    Bijective,
+    Mixed(MixedRadix),
+    Beta(RealRadix),
+    Zeckendorf,
+    FibonacciCode,
+    Phinary,
+    Roman,
+    Code(BinaryCode),
+}
+
+impl Numeration {
+    fn label(&self, base: u32) -> String {
+        match self {
+            Numeration::Positional => format!("base {}", base),
+            Numeration::Balanced => format!("balanced base {}", base),
+            Numeration::Bijective => format!("bijective base {}", base),
+            Numeration::Mixed(radix) => format!("mixed radix {}", radix.name()),
+            Numeration::Beta(radix) => format!("base {}", radix.name),
+            Numeration::Zeckendorf => "Zeckendorf".to_string(),
+            Numeration::FibonacciCode => "Fibonacci code".to_string(),
+            Numeration::Phinary => "base φ".to_string(),
+            Numeration::Roman => "Roman numeral".to_string(),
+            Numeration::Code(code) => code.name().to_string(),
+        }
+    }
 }
 
-fn convert_number(value: &str, from_base: u32, to_base: u32, precision: usize) {
-    if from_base < 2 || from_base > 36 {
-        eprintln!("Error: Source base must be between 2 and 36");
+struct Settings {
+    precision: usize,
+    repeat: Option<RepeatNotation>,
+    rounding: RoundingMode,
+    bits: Option<u32>,
+    encoding: SignedEncoding,
+    signed_input: bool,
+    from_alphabet: Alphabet,
+    to_alphabet: Alphabet,
+    lowercase: bool,
+    grouping: Option<DigitGrouping>,
+    scientific: Option<usize>,
+    qformat: Option<QFormat>,
+    from_numeration: Numeration,
+    to_numeration: Numeration,
+    from_complex: Option<ComplexBase>,
+    to_complex: Option<ComplexBase>,
+    lenient_roman: bool,
+}
+
+impl Settings {
+    fn output_options(&self, notation: RepeatNotation) -> FormatOptions {
+        FormatOptions {
+            alphabet: self.to_alphabet.clone(),
+            notation,
+            lowercase: self.lowercase,
+            grouping: self.grouping,
+        }
+    }
+}
+
+fn resolve_alphabet(side: Option<&str>, shared: Option<&str>) -> Result<Alphabet, String> {
+    match side.or(shared) {
+        Some(spec) => Alphabet::from_spec(spec),
+        None => Ok(Alphabet::standard()),
+    }
+}
+
+fn build_settings(args: &Args) -> Result<Settings, String> {
+    let mut from_alphabet =
+        resolve_alphabet(args.from_alphabet.as_deref(), args.alphabet.as_deref())?;
+    if args.case_sensitive {
+        from_alphabet = from_alphabet.case_sensitive();
+    } else if args.ignore_case {
+        from_alphabet = from_alphabet.case_insensitive()?;
+    }
+
+    let to_alphabet = resolve_alphabet(args.to_alphabet.as_deref(), args.alphabet.as_deref())?;
+    if args.lowercase && to_alphabet.is_case_sensitive() {
+        return Err("--lowercase needs a case-insensitive output alphabet".to_string());
+    }
+
+    if args.scientific == Some(0) {
+        return Err("--scientific needs at least 1 significant digit".to_string());
+    }
+
+    let grouping = match args.group {
+        Some(0) => return Err("--group needs a group size of at least 1".to_string()),
+        Some(size) => Some(DigitGrouping {
+            size,
+            separator: args.group_separator,
+        }),
+        None => None,
+    };
+    let separator = args.group_separator;
+    if grouping.is_some()
+        && (matches!(separator, '.' | '(' | ')' | '-')
+            || to_alphabet.digit_value(separator).is_some())
+    {
+        return Err(format!(
+            "'{}' cannot separate digit groups: it would be read as part of the number",
+            separator
+        ));
+    }
+
+    let from_numeration = match (
+        args.from_system,
+        args.from_code,
+        &args.from_radix,
+        &args.from_beta,
+        args.from_balanced,
+        args.from_bijective,
+    ) {
+        (Some(system), ..) => system.into(),
+        (_, Some(code), ..) => Numeration::Code(code.into()),
+        (_, _, Some(spec), ..) => Numeration::Mixed(MixedRadix::parse(spec)?),
+        (_, _, _, Some(spec), ..) => Numeration::Beta(RealRadix::parse(spec)?),
+        (_, _, _, _, true, _) => Numeration::Balanced,
+        (_, _, _, _, _, true) => Numeration::Bijective,
+        _ => Numeration::Positional,
+    };
+    let to_numeration = match (
+        args.to_system,
+        args.code,
+        &args.to_radix,
+        &args.to_beta,
+        args.to_balanced,
+        args.bijective,
+    ) {
+        (Some(system), ..) => system.into(),
+        (_, Some(code), ..) => Numeration::Code(code.into()),
+        (_, _, Some(spec), ..) => Numeration::Mixed(MixedRadix::parse(spec)?),
+        (_, _, _, Some(spec), ..) => Numeration::Beta(RealRadix::parse(spec)?),
+        (_, _, _, _, true, _) => Numeration::Balanced,
+        (_, _, _, _, _, true) => Numeration::Bijective,
+        _ => Numeration::Positional,
+    };
+
+    Ok(Settings {
+        precision: args.precision,
+        repeat: args.repeat.map(RepeatNotation::from),
+        rounding: args.rounding.into(),
+        bits: args.bits,
+        encoding: args.encoding.into(),
+        signed_input: args.signed_input,
+        from_alphabet,
+        to_alphabet,
+        lowercase: args.lowercase,
+        grouping,
+        scientific: args.scientific,
+        qformat: args.qformat.as_deref().map(QFormat::parse).transpose()?,
+        from_numeration,
+        to_numeration,
+        from_complex: args
+            .from_complex
+            .as_deref()
+            .map(ComplexBase::parse)
+            .transpose()?,
+        to_complex: args
+            .to_complex
+            .as_deref()
+            .map(ComplexBase::parse)
+            .transpose()?,
+        lenient_roman: args.lenient_roman,
+    })
+}
+
+fn decode_input(number: Number, base: u32, settings: &Settings) -> Result<Number, String> {
+    match settings.bits {
+        Some(bits) if settings.signed_input => {
+            if number.negative {
+                return Err("A signed bit pattern cannot carry a '-' sign".to_string());
+            }
+            if !number.fractional.is_empty() || !number.repetend.is_empty() {
+                return Err("A signed bit pattern must be an integer".to_string());
+            }
+            decode_signed(&number.integer, base, bits, settings.encoding)
+        }
+        _ => Ok(number),
+    }
+}
+
+fn render_result(
+    number: &Number,
+    from_base: u32,
+    to_base: u32,
+    settings: &Settings,
+) -> Result<String, String> {
+    if let Some(bits) = settings.bits.filter(|_| !settings.signed_input) {
+        let converted = convert_number(
+            number,
+            from_base,
+            to_base,
+            settings.precision,
+            RoundingMode::TowardZero,
+        );
+        let pattern = encode_signed(&converted, to_base, bits, settings.encoding)?;
+        return Ok(format_number_with(
+            &Number::new(pattern, Vec::new()),
+            &settings.output_options(RepeatNotation::default()),
+        ));
+    }
+
+    if let Some(digits) = settings.scientific {
+        let scientific = convert_scientific(number, from_base, to_base, digits, settings.rounding);
+        return Ok(format_scientific(
+            &scientific,
+            to_base,
+            &settings.output_options(RepeatNotation::default()),
+        ));
+    }
+
+    Ok(match settings.repeat {
+        Some(notation) => {
+            let expansion = expand_number(number, from_base, to_base, settings.precision);
+            format_expansion_with(&expansion, &settings.output_options(notation))
+        }
+        None => {
+            let converted = convert_number(
+                number,
+                from_base,
+                to_base,
+                settings.precision,
+                settings.rounding,
+            );
+            format_number_with(
+                &converted,
+                &settings.output_options(RepeatNotation::default()),
+            )
+        }
+    })
+}
+
+fn alphabet_hint(base: u32, max_base: u32) -> &'static str {
+    if base > max_base {
+        " (larger bases need a longer --alphabet)"
+    } else {
+        ""
+    }
+}
+
+fn render_signed_radix(
+    number: &Number,
+    from_radix: i32,
+    to_radix: i32,
+    settings: &Settings,
+) -> Result<String, String> {
+    if settings.bits.is_some() || settings.scientific.is_some() {
+        return Err("Negative bases cannot be combined with --bits or --scientific".to_string());
+    }
+
+    match settings.repeat {
+        Some(notation) if to_radix > 0 => {
+            let expansion =
+                expand_signed_radix(number, from_radix, to_radix as u32, settings.precision);
+            Ok(format_expansion_with(
+                &expansion,
+                &settings.output_options(notation),
+            ))
+        }
+        Some(_) => Err("--repeat is not supported for a negative target base".to_string()),
+        None => {
+            let converted = convert_signed_radix(
+                number,
+                from_radix,
+                to_radix,
+                settings.precision,
+                settings.rounding,
+            );
+            Ok(format_number_with(
+                &converted,
+                &settings.output_options(RepeatNotation::default()),
+            ))
+        }
+    }
+}
+
+fn base_range(max_base: u32) -> String {
+    format!("-{} and -2 or between 2 and {}", max_base, max_base)
+}
+
+fn run_conversion(value: &str, from_base: Option<i32>, to_base: i32, settings: &Settings) {
+    // Radix markers name positive bases, so a negative base reads the value as written.
+    let detected = match from_base {
+        Some(base) if base < 0 => Ok((base, value.to_string())),
+        _ => detect_radix(
+            value,
+            from_base.map(i32::unsigned_abs),
+            &settings.from_alphabet,
+        )
+        .map(|(base, digits)| (base as i32, digits)),
+    };
+    let (from_base, digits) = match detected {
+        Ok(detected) => detected,
+        Err(e) => {
+            eprintln!("Error parsing input: {}", e);
+            return;
+        }
+    };
+
+    let max_from = settings.from_alphabet.max_base();
+    if !(2..=max_from).contains(&from_base.unsigned_abs()) {
+        eprintln!(
+            "Error: Source base must be between {}{}",
+            base_range(max_from),
+            alphabet_hint(from_base.unsigned_abs(), max_from)
+        );
         return;
     }
-    
-    if to_base < 2 || to_base > 36 {
-        eprintln!("Error: Target base must be between 2 and 36");
+
+    let max_to = settings.to_alphabet.max_base();
+    if !(2..=max_to).contains(&to_base.unsigned_abs()) {
+        eprintln!(
+            "Error: Target base must be between {}{}",
+            base_range(max_to),
+            alphabet_hint(to_base.unsigned_abs(), max_to)
+        );
         return;
     }
 
-    let (integer_part, fractional_part) = match parse_number(value, from_base) {
-        Ok(parts) => parts,
+    let number = match parse_signed_radix(&digits, from_base, &settings.from_alphabet) {
+        Ok(number) => number,
         Err(e) => {
             eprintln!("Error parsing input: {}", e);
             return;
         }
     };
 
-    let converted_integer = convert_integer_part(&integer_part, from_base, to_base);
-    let converted_fractional = convert_fractional_part(
-        &fractional_part,
-        from_base,
-        to_base,
-        precision,
-    );
+    let negative_radix = from_base < 0 || to_base < 0;
+    let number = if negative_radix {
+        number
+    } else {
+        match decode_input(number, from_base as u32, settings) {
+            Ok(number) => number,
+            Err(e) => {
+                eprintln!("Error decoding input: {}", e);
+                return;
+            }
+        }
+    };
+
+    let rendered = if negative_radix {
+        render_signed_radix(&number, from_base, to_base, settings)
+    } else {
+        render_result(&number, from_base as u32, to_base as u32, settings)
+    };
+    let result = match rendered {
+        Ok(result) => result,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return;
+        }
+    };
 
-    let result = format_result(&converted_integer, &converted_fractional);
-    
     println!("Input: {} (base {})", value, from_base);
-    println!("Result: {} (base {})", result, to_base);
-    
+    match settings.bits.filter(|_| !settings.signed_input) {
+        Some(bits) => println!(
+            "Result: {} (base {}, {}-bit {})",
+            result,
+            to_base,
+            bits,
+            settings.encoding.name()
+        ),
+        None => println!("Result: {} (base {})", result, to_base),
+    }
+
     if from_base != 10 || to_base != 10 {
-        let decimal_integer = convert_integer_part(&integer_part, from_base, 10);
-        let decimal_fractional = convert_fractional_part(&fractional_part, from_base, 10, 10);
-        let decimal_result = format_result(&decimal_integer, &decimal_fractional);
+        let decimal = convert_signed_radix(&number, from_base, 10, 10, RoundingMode::TowardZero);
+        let decimal_result = format_number(&decimal);
         println!("Decimal: {}", decimal_result);
     }
 }
 
+/// The symbols of one side: the numeration's defaults for the base unless a
+/// custom alphabet was given.
+fn side_symbols(
+    alphabet: &Alphabet,
+    numeration: &Numeration,
+    base: u32,
+) -> Result<Alphabet, String> {
+    let custom = *alphabet != Alphabet::standard();
+    match numeration {
+        Numeration::Balanced if !custom => balanced_alphabet(base),
+        Numeration::Balanced => {
+            check_balanced_base(base, alphabet)?;
+            Ok(alphabet.clone())
+        }
+        Numeration::Bijective if !custom => bijective_alphabet(base),
+        Numeration::Bijective => {
+            check_bijective_base(base, alphabet)?;
+            Ok(alphabet.clone())
+        }
+        _ => Ok(alphabet.clone()),
+    }
+}
+
+/// Checks that the alphabet has a symbol for every digit of a beta-expansion.
+fn check_beta_digits(radix: &RealRadix, alphabet: &Alphabet) -> Result<(), String> {
+    if radix.digit_count() > alphabet.max_base() {
+        return Err(format!(
+            "Base {} needs {} digit symbols, but the alphabet has {}",
+            radix.name,
+            radix.digit_count(),
+            alphabet.max_base()
+        ));
+    }
+    Ok(())
+}
+
+/// The magnitude of an input that a system can only write as an integer.
+fn integer_magnitude(input: &Input, system: &str) -> Result<BigUint, String> {
+    input
+        .number
+        .integer_value(input.base)
+        .map_err(|_| format!("{} needs an integer value", system))
+}
+
+/// Reads 0/1 digits written for a Fibonacci-based system.
+fn read_binary_integer(value: &str, system: &str) -> Result<Number, String> {
+    let number = parse_number_full(value, 2)?;
+    if !number.fractional.is_empty() || !number.repetend.is_empty() {
+        return Err(format!("{} has no fractional digits", system));
+    }
+    Ok(number)
+}
+
+fn decimal_input(label: String, negative: bool, magnitude: &BigUint) -> Input {
+    Input {
+        label,
+        base: 10,
+        number: Number {
+            negative,
+            ..Number::new(magnitude.to_digits(10), Vec::new())
+        },
+    }
+}
+
+/// A parsed input: its value as an ordinary number in `base`, and a label
+/// naming the system it was written in.
+struct Input {
+    label: String,
+    base: u32,
+    number: Number,
+}
+
+fn read_input(value: &str, from_base: Option<i32>, settings: &Settings) -> Result<Input, String> {
+    let numeration = &settings.from_numeration;
+    if from_base.is_some_and(|base| base < 0) {
+        return Err(
+            "Negative bases cannot be combined with --from-balanced or --from-bijective"
+                .to_string(),
+        );
+    }
+
+    // clap requires --from-base along with --from-balanced and --from-bijective
+    let written_base = from_base.unwrap_or_default() as u32;
+    let label = numeration.label(written_base);
+    match numeration {
+        Numeration::Balanced => {
+            let alphabet = side_symbols(&settings.from_alphabet, numeration, written_base)?;
+            let digits = parse_balanced(value, written_base, &alphabet)?;
+            Ok(Input {
+                label,
+                base: written_base,
+                number: from_balanced(&digits, written_base),
+            })
+        }
+        Numeration::Bijective => {
+            let alphabet = side_symbols(&settings.from_alphabet, numeration, written_base)?;
+            let parsed = parse_bijective(value, written_base, &alphabet)?;
+            let magnitude = parsed.magnitude(written_base);
+            Ok(decimal_input(label, parsed.negative, &magnitude))
+        }
+        Numeration::Mixed(radix) => {
+            let digits = parse_mixed_radix(value, radix)?;
+            Ok(Input {
+                label,
+                base: 10,
+                number: convert_from_mixed_radix(&digits, radix, 10),
+            })
+        }
+        Numeration::Beta(radix) => {
+            let alphabet = &settings.from_alphabet;
+            check_beta_digits(radix, alphabet)?;
+            let digits = parse_beta(value, radix, alphabet)?;
+            let (base, number) = beta_to_positional(&digits, radix, settings.precision);
+            Ok(Input {
+                label,
+                base,
+                number,
+            })
+        }
+        Numeration::Zeckendorf => {
+            let number = read_binary_integer(value, "Zeckendorf")?;
+            let magnitude = from_zeckendorf(&number.integer)?;
+            Ok(decimal_input(label, number.negative, &magnitude))
+        }
+        Numeration::FibonacciCode => {
+            let number = read_binary_integer(value, "Fibonacci code")?;
+            if number.negative {
+                return Err("A Fibonacci code cannot carry a sign".to_string());
+            }
+            let values = fibonacci_decode(&number.integer)?;
+            if values.len() > 1 {
+                return Err(format!(
+                    "Expected one Fibonacci codeword, found {}",
+                    values.len()
+                ));
+            }
+            Ok(decimal_input(label, false, &values[0]))
+        }
+        Numeration::Code(code) => {
+            let number = read_binary_integer(value, "A binary code")?;
+            if number.negative {
+                return Err(format!("A {} cannot carry a sign", code.name()));
+            }
+            let magnitude = decode_binary_code(&number.integer, *code)?;
+            Ok(decimal_input(label, false, &magnitude))
+        }
+        Numeration::Roman => {
+            let magnitude = from_roman(value, settings.lenient_roman)?;
+            Ok(decimal_input(label, false, &magnitude))
+        }
+        Numeration::Phinary => {
+            let number = parse_number_full(value, 2)?;
+            let magnitude = from_phinary(&number)?;
+            Ok(decimal_input(label, number.negative, &magnitude))
+        }
+        Numeration::Positional => {
+            let alphabet = &settings.from_alphabet;
+            let (base, digits) = detect_radix(value, from_base.map(|base| base as u32), alphabet)?;
+            if !(2..=alphabet.max_base()).contains(&base) {
+                return Err(format!(
+                    "Source base must be between 2 and {}",
+                    alphabet.max_base()
+                ));
+            }
+            let number = decode_input(parse_number_with(&digits, base, alphabet)?, base, settings)?;
+            Ok(Input {
+                label: numeration.label(base),
+                base,
+                number,
+            })
+        }
+    }
+}
+
+/// Writes the input in the target system, returning the result and a label
+/// naming that system.
+fn render_output(
+    input: &Input,
+    to_base: Option<i32>,
+    settings: &Settings,
+) -> Result<(String, String), String> {
+    let numeration = &settings.to_numeration;
+    let binary = |digits: Vec<u32>, fractional: Vec<u32>, negative: bool| {
+        let number = Number {
+            negative,
+            ..Number::new(digits, fractional)
+        };
+        format_number_with(&number, &settings.output_options(RepeatNotation::default()))
+    };
+    let negative = input.number.negative && !input.number.is_zero();
+    let written = match numeration {
+        Numeration::Mixed(radix) => {
+            let digits = convert_to_mixed_radix(&input.number, input.base, radix)?;
+            Some(format_mixed_radix(&digits))
+        }
+        Numeration::Beta(radix) => {
+            check_beta_digits(radix, &settings.to_alphabet)?;
+            let expansion = beta_expand(&input.number, input.base, radix, settings.precision);
+            let options = settings.output_options(RepeatNotation::default());
+            let result = format_number_with(&expansion.digits, &options);
+            let mut label = numeration.label(0);
+            if radix.approximated() {
+                label = format!("{} ≈ {} digits", label, APPROXIMATION_DIGITS);
+            }
+            if !expansion.exact {
+                label = format!("{}, truncated", label);
+            }
+            return Ok((result, label));
+        }
+        Numeration::Zeckendorf => {
+            let magnitude = integer_magnitude(input, "Zeckendorf")?;
+            Some(binary(to_zeckendorf(&magnitude), Vec::new(), negative))
+        }
+        Numeration::FibonacciCode => {
+            let magnitude = integer_magnitude(input, "Fibonacci coding")?;
+            if negative {
+                return Err("Fibonacci coding only covers positive integers".to_string());
+            }
+            Some(binary(fibonacci_encode(&magnitude)?, Vec::new(), false))
+        }
+        Numeration::Code(code) => {
+            let magnitude = integer_magnitude(input, "Binary code output")?;
+            if negative {
+                return Err(format!("Cannot write a negative value in {}", code.name()));
+            }
+            Some(binary(
+                encode_binary_code(&magnitude, *code),
+                Vec::new(),
+                false,
+            ))
+        }
+        Numeration::Roman => {
+            let magnitude = integer_magnitude(input, "Roman numeral output")?;
+            if negative {
+                return Err("Roman numerals have no negative values".to_string());
+            }
+            Some(to_roman(&magnitude)?)
+        }
+        Numeration::Phinary => {
+            let magnitude = integer_magnitude(input, "Base φ output")?;
+            let phinary = to_phinary(&magnitude);
+            Some(binary(phinary.integer, phinary.fractional, negative))
+        }
+        _ => None,
+    };
+    if let Some(written) = written {
+        return Ok((written, numeration.label(0)));
+    }
+
+    let to_base = match to_base {
+        Some(base) if base < 0 => {
+            return Err(
+                "Negative bases cannot be combined with --to-balanced or --bijective".to_string(),
+            );
+        }
+        Some(base) => base as u32,
+        None => return Err("--to-base is required when not in interactive mode".to_string()),
+    };
+    let options = FormatOptions {
+        alphabet: side_symbols(&settings.to_alphabet, numeration, to_base)?,
+        ..settings.output_options(RepeatNotation::default())
+    };
+    let result = match numeration {
+        Numeration::Balanced => {
+            let digits = to_balanced(
+                &input.number,
+                input.base,
+                to_base,
+                settings.precision,
+                settings.rounding,
+            );
+            format_balanced(&digits, to_base, &options)
+        }
+        Numeration::Bijective => {
+            let magnitude = integer_magnitude(input, "Bijective numeration")?;
+            let bijective = Bijective::from_integer(input.number.negative, &magnitude, to_base)?;
+            format_bijective(&bijective, &options)
+        }
+        _ => {
+            let max_to = settings.to_alphabet.max_base();
+            if !(2..=max_to).contains(&to_base) {
+                return Err(format!("Target base must be between 2 and {}", max_to));
+            }
+            render_result(&input.number, input.base, to_base, settings)?
+        }
+    };
+    Ok((result, numeration.label(to_base)))
+}
+
+fn run_numeration(value: &str, from_base: Option<i32>, to_base: Option<i32>, settings: &Settings) {
+    let rendered = read_input(value, from_base, settings)
+        .and_then(|input| Ok((render_output(&input, to_base, settings)?, input)));
+    let ((result, label), input) = match rendered {
+        Ok(rendered) => rendered,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return;
+        }
+    };
+
+    let result = if result.is_empty() {
+        "(empty)"
+    } else {
+        &result
+    };
+    println!("Input: {} ({})", value, input.label);
+    println!("Result: {} ({})", result, label);
+    let decimal = convert_number(&input.number, input.base, 10, 10, RoundingMode::TowardZero);
+    println!("Decimal: {}", format_number(&decimal));
+}
+
+/// Reads a complex value, as decimal `a+bi` or in a complex base, returning
+/// it in decimal with a label naming the system it was written in.
+fn read_complex(
+    value: &str,
+    from_base: Option<i32>,
+    settings: &Settings,
+) -> Result<(ComplexNumber, String), String> {
+    if from_base.is_some_and(|base| base != 10) {
+        return Err("Complex values are read in decimal or a complex base".to_string());
+    }
+    match &settings.from_complex {
+        Some(base) => {
+            check_complex_digits(base, &settings.from_alphabet)?;
+            let digits = parse_complex_base(value, base, &settings.from_alphabet)?;
+            let decimal = from_complex_base(&digits, base, settings.precision, settings.rounding);
+            Ok((decimal, format!("base {}", base.name())))
+        }
+        None => Ok((parse_complex(value)?, "base 10".to_string())),
+    }
+}
+
+/// Writes a complex value in a complex base or as decimal `a+bi`.
+fn render_complex(
+    decimal: &ComplexNumber,
+    to_base: Option<i32>,
+    settings: &Settings,
+) -> Result<(String, String), String> {
+    if to_base.is_some_and(|base| base != 10) {
+        return Err("Complex values are written in decimal or a complex base".to_string());
+    }
+    match &settings.to_complex {
+        Some(base) => {
+            check_complex_digits(base, &settings.to_alphabet)?;
+            let digits = to_complex_base(decimal, base, settings.precision, settings.rounding);
+            let options = settings.output_options(RepeatNotation::default());
+            Ok((
+                format_number_with(&digits, &options),
+                format!("base {}", base.name()),
+            ))
+        }
+        None => Ok((format_complex(decimal), "base 10".to_string())),
+    }
+}
+
+fn run_complex(value: &str, from_base: Option<i32>, to_base: Option<i32>, settings: &Settings) {
+    let rendered = read_complex(value, from_base, settings)
+        .and_then(|input| Ok((render_complex(&input.0, to_base, settings)?, input)));
+    let ((result, label), (decimal, input_label)) = match rendered {
+        Ok(rendered) => rendered,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return;
+        }
+    };
+
+    println!("Input: {} ({})", value, input_label);
+    println!("Result: {} ({})", result, label);
+    println!("Decimal: {}", format_complex(&decimal));
+}
+
+fn check_complex_digits(base: &ComplexBase, alphabet: &Alphabet) -> Result<(), String> {
+    if base.digit_count() > alphabet.max_base() {
+        return Err(format!(
+            "Base {} needs {} digit symbols, but the alphabet has {}",
+            base.name(),
+            base.digit_count(),
+            alphabet.max_base()
+        ));
+    }
+    Ok(())
+}
+
+/// Parses `value`, reading it as decimal when neither a source base nor a
+/// radix marker says otherwise.
+fn read_decimal_default(
+    value: &str,
+    from_base: Option<u32>,
+    settings: &Settings,
+) -> Result<(u32, Number), String> {
+    let alphabet = &settings.from_alphabet;
+    let from_base =
+        from_base.or_else(|| detect_radix(value, None, alphabet).is_err().then_some(10));
+    let (base, digits) = detect_radix(value, from_base, alphabet)?;
+    Ok((base, parse_number_with(&digits, base, alphabet)?))
+}
+
+fn read_float(
+    value: &str,
+    from_base: Option<u32>,
+    format: FloatFormat,
+    from_bits: bool,
+    settings: &Settings,
+) -> Result<FloatBits, String> {
+    if !from_bits && let Some(special) = special_float(value, format)? {
+        return Ok(special);
+    }
+
+    let (base, number) = read_decimal_default(value, from_base, settings)?;
+    if !from_bits {
+        return Ok(encode_float(&number, base, format));
+    }
+
+    if number.negative || !number.fractional.is_empty() || !number.repetend.is_empty() {
+        return Err("A raw bit pattern must be a non-negative integer".to_string());
+    }
+    let bits = BigUint::from_digits(&number.integer, base)
+        .to_u128()
+        .ok_or_else(|| {
+            format!(
+                "Pattern does not fit in the {} bits of {}",
+                format.total_bits(),
+                format.name()
+            )
+        })?;
+    FloatBits::from_bits(format, bits)
+}
+
+fn hex_digits(bits: u32) -> usize {
+    bits.div_ceil(4) as usize
+}
+
+fn print_float(float: &FloatBits) {
+    let format = float.format;
+    let class = match float.classify() {
+        FloatClass::Zero => "zero",
+        FloatClass::Subnormal => "subnormal",
+        FloatClass::Normal => "normal",
+        FloatClass::Infinite => "infinity",
+        FloatClass::QuietNan => "quiet NaN",
+        FloatClass::SignalingNan => "signaling NaN",
+    };
+    let exponent_bits = format.exponent_bits() as usize;
+    let fraction_bits = format.fraction_bits() as usize;
+
+    println!("Format: {} ({})", format.name(), class);
+    println!(
+        "Bits: 0x{:0width$X}",
+        float.to_bits(),
+        width = hex_digits(format.total_bits())
+    );
+    println!(
+        "Sign: {} ({})",
+        float.sign as u8,
+        if float.sign { "-" } else { "+" }
+    );
+    println!(
+        "Exponent: 0x{:0hex$X} = {:0exponent_bits$b} (biased {}, unbiased {})",
+        float.exponent,
+        float.exponent,
+        float.exponent,
+        float.unbiased_exponent(),
+        hex = hex_digits(format.exponent_bits()),
+    );
+    println!(
+        "Mantissa: 0x{:0hex$X} = {:0fraction_bits$b}",
+        float.fraction,
+        float.fraction,
+        hex = hex_digits(format.fraction_bits()),
+    );
+
+    let sign = if float.sign { "-" } else { "" };
+    match (float.value(), float.nan_payload()) {
+        (Some(value), _) => println!("Exact: {}", exact_decimal(&value)),
+        (None, Some(payload)) => println!("Exact: {}NaN (payload 0x{:X})", sign, payload),
+        (None, None) => println!("Exact: {}inf", sign),
+    }
+}
+
+fn run_float(
+    value: &str,
+    from_base: Option<u32>,
+    format: FloatFormat,
+    from_bits: bool,
+    settings: &Settings,
+) {
+    match read_float(value, from_base, format, from_bits, settings) {
+        Ok(float) => {
+            println!("Input: {}", value);
+            print_float(&float);
+        }
+        Err(e) => eprintln!("Error: {}", e),
+    }
+}
+
+fn run_fixed(value: &str, from_base: Option<u32>, from_word: bool, settings: &Settings) {
+    let format = settings.qformat.unwrap();
+    let (base, number) = match read_decimal_default(value, from_base, settings) {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            eprintln!("Error parsing input: {}", e);
+            return;
+        }
+    };
+
+    let quantized = if from_word {
+        match dequantize(&number, base, format) {
+            Ok(quantized) => quantized,
+            Err(e) => {
+                eprintln!("Error: {}", e);
+                return;
+            }
+        }
+    } else {
+        quantize(&number, base, format, settings.rounding)
+    };
+
+    let signedness = if format.signed { "signed" } else { "unsigned" };
+    println!("Input: {} (base {})", value, base);
+    println!(
+        "Format: {} ({}-bit {}, {} fraction bits)",
+        format.name(),
+        format.total_bits(),
+        signedness,
+        format.fraction_bits
+    );
+    println!("Integer: {}", format_number(&quantized.integer(10)));
+    println!(
+        "Word: 0x{}",
+        format_number(&Number::new(quantized.word(16), Vec::new()))
+    );
+    println!("Value: {}", exact_decimal(&quantized.value()));
+    if !from_word {
+        let error = quantized.error(&number, base, 10, settings.precision);
+        println!(
+            "Error: {}",
+            format_expansion_with(&error, &FormatOptions::default())
+        );
+    }
+    if quantized.saturated {
+        println!("Saturated: input is outside the {} range", format.name());
+    }
+}
+
 fn read_line(prompt: &str) -> String {
     print!("{}", prompt);
     io::stdout().flush().unwrap();
@@ -70,21 +1355,23 @@ fn read_line(prompt: &str) -> String {
     input.trim().to_string()
 }
 
-fn parse_base(input: &str) -> Option<u32> {
-    input.parse::<u32>().ok()
+fn parse_base(input: &str) -> Option<i32> {
+    input.parse::<i32>().ok()
 }
 
-fn interactive_mode(precision: usize) {
+fn interactive_mode(settings: &Settings) {
+    let max_from = settings.from_alphabet.max_base();
+    let max_to = settings.to_alphabet.max_base();
     println!("Base Converter - Interactive Mode");
     println!("Type 'quit' or 'exit' to leave, 'help' for instructions\n");
 
     loop {
         let value = read_line("Enter number to convert (or command): ");
-        
+
         if value.is_empty() {
             continue;
         }
-        
+
         match value.to_lowercase().as_str() {
             "quit" | "exit" => {
                 println!("Goodbye!");
@@ -93,59 +1380,111 @@ fn interactive_mode(precision: usize) {
             "help" => {
                 println!("\nHow to use:");
                 println!("1. Enter the number you want to convert");
-                println!("2. Enter the source base (2-36)");
-                println!("3. Enter the target base (2-36)");
+                println!(
+                    "2. Enter the source base (2-{}, or negative for a negabase), or leave it blank for prefixed values like 0xFF",
+                    max_from
+                );
+                println!(
+                    "3. Enter the target base (2-{}, or negative for a negabase)",
+                    max_to
+                );
                 println!("Commands: 'quit'/'exit' to leave, 'help' for this message\n");
                 continue;
             }
             _ => {}
         }
 
-        let from_base_str = read_line("Enter source base (2-36): ");
+        let from_base_str = read_line(&format!(
+            "Enter source base (2-{}, blank to detect): ",
+            max_from
+        ));
         let from_base = match parse_base(&from_base_str) {
-            Some(base) if base >= 2 && base <= 36 => base,
+            _ if from_base_str.is_empty() => None,
+            Some(base) if (2..=max_from).contains(&base.unsigned_abs()) => Some(base),
             _ => {
-                eprintln!("Invalid source base. Please enter a number between 2 and 36.\n");
+                eprintln!(
+                    "Invalid source base. Please enter a number between {}.\n",
+                    base_range(max_from)
+                );
                 continue;
             }
         };
 
-        let to_base_str = read_line("Enter target base (2-36): ");
+        let to_base_str = read_line(&format!("Enter target base (2-{}): ", max_to));
         let to_base = match parse_base(&to_base_str) {
-            Some(base) if base >= 2 && base <= 36 => base,
+            Some(base) if (2..=max_to).contains(&base.unsigned_abs()) => base,
             _ => {
-                eprintln!("Invalid target base. Please enter a number between 2 and 36.\n");
+                eprintln!(
+                    "Invalid target base. Please enter a number between {}.\n",
+                    base_range(max_to)
+                );
                 continue;
             }
         };
 
         println!();
-        convert_number(&value, from_base, to_base, precision);
+        run_conversion(&value, from_base, to_base, settings);
         println!();
     }
 }
 
+/// Reads the value for `--value -` from stdin, for values longer than a
+/// command-line argument may be.
+fn read_stdin_value() -> Result<String, String> {
+    let mut value = String::new();
+    io::stdin()
+        .read_to_string(&mut value)
+        .map_err(|e| format!("Cannot read the value from stdin: {}", e))?;
+    Ok(value.trim().to_string())
+}
+
 fn main() {
     let args = Args::parse();
+    let settings = build_settings(&args).unwrap_or_else(|e| {
+        eprintln!("Error: {}", e);
+        std::process::exit(1);
+    });
 
     if args.interactive {
-        interactive_mode(args.precision);
-    } else {
-        let value = args.value.unwrap_or_else(|| {
+        interactive_mode(&settings);
+        return;
+    }
+
+    let value = match args.value.as_deref() {
+        Some("-") => read_stdin_value().unwrap_or_else(|e| {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        }),
+        Some(value) => value.to_string(),
+        None => {
             eprintln!("Error: --value is required when not in interactive mode");
             std::process::exit(1);
-        });
-        
-        let from_base = args.from_base.unwrap_or_else(|| {
-            eprintln!("Error: --from-base is required when not in interactive mode");
+        }
+    };
+    let value = value.as_str();
+
+    if settings.qformat.is_some() || args.float.is_some() {
+        if args.from_base.is_some_and(|base| base < 0) {
+            eprintln!("Error: Negative bases are only supported for base conversion");
             std::process::exit(1);
-        });
-        
+        }
+        let from_base = args.from_base.map(i32::unsigned_abs);
+        match args.float {
+            Some(float) => run_float(value, from_base, float.into(), args.from_bits, &settings),
+            None => run_fixed(value, from_base, args.from_word, &settings),
+        }
+    } else if settings.from_complex.is_some() || settings.to_complex.is_some() {
+        run_complex(value, args.from_base, args.to_base, &settings);
+    } else if settings.from_numeration != Numeration::Positional
+        || settings.to_numeration != Numeration::Positional
+    {
+        run_numeration(value, args.from_base, args.to_base, &settings);
+    } else {
         let to_base = args.to_base.unwrap_or_else(|| {
             eprintln!("Error: --to-base is required when not in interactive mode");
             std::process::exit(1);
         });
 
-        convert_number(&value, from_base, to_base, args.precision);
+        run_conversion(value, args.from_base, to_base, &settings);
     }
 }
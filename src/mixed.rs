@@ -0,0 +1,184 @@
+use crate::alphabet::Alphabet;
+use crate::bigint::BigUint;
+use crate::number::Number;
+use crate::parser::{parse_digits, split_sign};
+
+/// The place values of a mixed-radix system.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MixedRadix {
+    /// Radices of the lower places, most significant first, below one
+    /// unbounded leading place: `[24, 60, 60]` is days:hours:minutes:seconds.
+    Fixed(Vec<u32>),
+    /// The factorial number system, where place `i` from the right has radix
+    /// `i + 1`.
+    Factorial,
+}
+
+impl MixedRadix {
+    /// Reads colon-separated radices such as `24:60:60` or `_:24:60:60`,
+    /// where a leading `_` marks the unbounded place, or one of the presets
+    /// `factoradic` and `time` (`_:24:60:60`).
+    pub fn parse(spec: &str) -> Result<Self, String> {
+        match spec.to_lowercase().as_str() {
+            "factoradic" | "factorial" => return Ok(MixedRadix::Factorial),
+            "time" => return Ok(MixedRadix::Fixed(vec![24, 60, 60])),
+            _ => {}
+        }
+
+        let fields = spec.strip_prefix("_:").unwrap_or(spec);
+        let radices = fields
+            .split(':')
+            .map(|field| match field.trim().parse::<u32>() {
+                Ok(radix) if radix >= 2 => Ok(radix),
+                Ok(radix) => Err(format!("Radix {} in '{}' must be at least 2", radix, spec)),
+                Err(_) => Err(format!("Invalid mixed-radix spec '{}'", spec)),
+            })
+            .collect::<Result<Vec<u32>, String>>()?;
+        Ok(MixedRadix::Fixed(radices))
+    }
+
+    pub fn name(&self) -> String {
+        match self {
+            MixedRadix::Fixed(radices) => {
+                let radices: Vec<String> = radices.iter().map(u32::to_string).collect();
+                format!("_:{}", radices.join(":"))
+            }
+            MixedRadix::Factorial => "factoradic".to_string(),
+        }
+    }
+
+    /// The radix of the place `place` digits from the right, or `None` for
+    /// the unbounded leading place.
+    pub fn radix(&self, place: usize) -> Option<u32> {
+        match self {
+            MixedRadix::Fixed(radices) if place < radices.len() => {
+                Some(radices[radices.len() - 1 - place])
+            }
+            MixedRadix::Fixed(_) => None,
+            MixedRadix::Factorial => Some(place as u32 + 1),
+        }
+    }
+}
+
+/// An integer written in a [`MixedRadix`] system, most significant digit
+/// first. Only the leading digit of a fixed system can exceed 32 bits.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct MixedDigits {
+    pub negative: bool,
+    pub digits: Vec<BigUint>,
+}
+
+impl MixedDigits {
+    /// Writes `±magnitude` in `radix`, without leading zero digits.
+    pub fn from_integer(negative: bool, magnitude: &BigUint, radix: &MixedRadix) -> Self {
+        let mut rest = magnitude.clone();
+        let mut digits = Vec::new();
+        let mut place = 0;
+        while let Some(base) = radix.radix(place) {
+            if rest.is_zero() {
+                break;
+            }
+            digits.push(BigUint::from_u64(rest.divrem_small(base) as u64));
+            place += 1;
+        }
+        if !rest.is_zero() || digits.is_empty() {
+            digits.push(rest);
+        }
+        digits.reverse();
+
+        MixedDigits {
+            negative: negative && !magnitude.is_zero(),
+            digits,
+        }
+    }
+
+    /// The magnitude of the value.
+    pub fn magnitude(&self, radix: &MixedRadix) -> BigUint {
+        let places = self.digits.len();
+        let mut value = BigUint::zero();
+        for (i, digit) in self.digits.iter().enumerate() {
+            if let Some(base) = radix.radix(places - 1 - i) {
+                value.mul_small(base);
+            }
+            value = &value + digit;
+        }
+        value
+    }
+}
+
+/// Reads colon-separated decimal digits such as `1:02:03:04`, aligned from
+/// the right with the places of `radix`. Every digit but an unbounded
+/// leading one must be below its radix.
+pub fn parse_mixed_radix(value: &str, radix: &MixedRadix) -> Result<MixedDigits, String> {
+    let decimal = Alphabet::standard();
+    let (sign, fields) = split_sign(value.trim(), &decimal);
+    let fields: Vec<&str> = fields.split(':').collect();
+
+    let mut digits = Vec::with_capacity(fields.len());
+    for (i, field) in fields.iter().enumerate() {
+        let place = fields.len() - 1 - i;
+        if field.is_empty() {
+            return Err("Empty digit in mixed-radix value".to_string());
+        }
+        let digit = BigUint::from_digits(&parse_digits(field, 10, &decimal)?, 10);
+        match radix.radix(place) {
+            Some(base) if digit >= BigUint::from_u64(base as u64) => {
+                return Err(format!(
+                    "Digit {} is too large for its place, which has radix {}",
+                    field, base
+                ));
+            }
+            None if i > 0 => {
+                return Err(format!("Too many digits for mixed radix {}", radix.name()));
+            }
+            _ => {}
+        }
+        digits.push(digit);
+    }
+
+    let negative = sign == "-" && digits.iter().any(|digit| !digit.is_zero());
+    Ok(MixedDigits { negative, digits })
+}
+
+/// Writes the digits in decimal, separated by colons.
+pub fn format_mixed_radix(number: &MixedDigits) -> String {
+    let fields: Vec<String> = number
+        .digits
+        .iter()
+        .map(|digit| {
+            digit
+                .to_digits(10)
+                .iter()
+                .map(|&d| char::from_digit(d, 10).unwrap())
+                .collect()
+        })
+        .collect();
+    let digits = fields.join(":");
+    if number.negative {
+        format!("-{}", digits)
+    } else {
+        digits
+    }
+}
+
+/// Converts an integer written in the ordinary base `from_base` to `radix`.
+pub fn convert_to_mixed_radix(
+    number: &Number,
+    from_base: u32,
+    radix: &MixedRadix,
+) -> Result<MixedDigits, String> {
+    let magnitude = number.integer_value(from_base)?;
+    Ok(MixedDigits::from_integer(
+        number.negative,
+        &magnitude,
+        radix,
+    ))
+}
+
+/// Writes a mixed-radix integer in the ordinary base `to_base`.
+pub fn convert_from_mixed_radix(number: &MixedDigits, radix: &MixedRadix, to_base: u32) -> Number {
+    Number {
+        negative: number.negative,
+        ..Number::new(number.magnitude(radix).to_digits(to_base), Vec::new())
+    }
+}
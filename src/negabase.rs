@@ -0,0 +1,176 @@
+use crate::bigint::BigUint;
+use crate::converter::{
+    Expansion, RoundingMode, convert_rational, expand_rational, place_point, rational_value,
+    round_scaled,
+};
+use crate::number::Number;
+
+/// A signed integer as a sign and a magnitude.
+pub(crate) type Signed = (bool, BigUint);
+
+pub(crate) fn add_signed((a_negative, a): Signed, (b_negative, b): Signed) -> Signed {
+    if a_negative == b_negative {
+        return (a_negative, &a + &b);
+    }
+    if a >= b {
+        let difference = &a - &b;
+        (a_negative && !difference.is_zero(), difference)
+    } else {
+        (b_negative, &b - &a)
+    }
+}
+
+pub(crate) fn mul_signed((a_negative, a): Signed, (b_negative, b): Signed) -> Signed {
+    let product = &a * &b;
+    (a_negative != b_negative && !product.is_zero(), product)
+}
+
+/// `(-radix)^exponent` as a signed integer.
+fn negative_power(radix: u32, exponent: usize) -> Signed {
+    (exponent % 2 == 1, BigUint::pow(radix, exponent))
+}
+
+/// Reads `digits` as an integer in base `-radix`: the even positions from the
+/// right add and the odd positions subtract.
+fn negabase_integer(digits: &[u32], radix: u32) -> Signed {
+    let mut even = digits.to_vec();
+    let mut odd = digits.to_vec();
+    for (position, (e, o)) in even.iter_mut().zip(odd.iter_mut()).rev().enumerate() {
+        if position % 2 == 0 {
+            *o = 0;
+        } else {
+            *e = 0;
+        }
+    }
+    add_signed(
+        (false, BigUint::from_digits(&even, radix)),
+        (true, BigUint::from_digits(&odd, radix)),
+    )
+}
+
+/// Writes a signed integer in base `-radix`, most significant digit first.
+fn to_negabase_integer((mut negative, mut magnitude): Signed, radix: u32) -> Vec<u32> {
+    let mut digits = Vec::new();
+    while !magnitude.is_zero() {
+        let remainder = magnitude.divrem_small(radix);
+        if negative && remainder != 0 {
+            // -m = q(-radix) + (radix - r) with q = m/radix + 1
+            digits.push(radix - remainder);
+            magnitude.add_small(1);
+        } else {
+            digits.push(remainder);
+        }
+        negative = !negative && !magnitude.is_zero();
+    }
+
+    if digits.is_empty() {
+        digits.push(0);
+    }
+    digits.reverse();
+    digits
+}
+
+/// The exact value of `number` read in `radix`, which may be negative, as a
+/// sign, numerator and denominator.
+fn signed_value(number: &Number, radix: i32) -> (bool, BigUint, BigUint) {
+    if radix > 0 {
+        let (numerator, denominator) = rational_value(number, radix as u32);
+        let negative = number.negative && !numerator.is_zero();
+        return (negative, numerator, denominator);
+    }
+
+    // With n written fraction digits, value * (-b)^n is the negabase integer
+    // of all written digits; a repetend of length L adds R / ((-b)^L - 1).
+    let b = radix.unsigned_abs();
+    let written = [number.integer.as_slice(), &number.fractional].concat();
+    let scale = negative_power(b, number.fractional.len());
+    let (numerator, denominator) = if number.repetend.is_empty() {
+        (negabase_integer(&written, b), scale)
+    } else {
+        let cycle = add_signed(
+            negative_power(b, number.repetend.len()),
+            (true, BigUint::one()),
+        );
+        let numerator = add_signed(
+            mul_signed(negabase_integer(&written, b), cycle.clone()),
+            negabase_integer(&number.repetend, b),
+        );
+        (numerator, mul_signed(scale, cycle))
+    };
+
+    let negative = (numerator.0 != denominator.0) != number.negative;
+    (
+        negative && !numerator.1.is_zero(),
+        numerator.1,
+        denominator.1,
+    )
+}
+
+/// Converts between bases that may be negative (`-36..=-2` or `2..=36`, or up
+/// to the alphabet size). Base `-b` uses the digits `0..b` and needs no sign,
+/// so a negative result is written without one.
+///
+/// A negative target is written with at most `precision` fraction digits,
+/// rounding the magnitude of the value scaled by `b^precision`; every value
+/// that terminates within that many digits comes out exactly.
+pub fn convert_signed_radix(
+    number: &Number,
+    from_radix: i32,
+    to_radix: i32,
+    precision: usize,
+    rounding: RoundingMode,
+) -> Number {
+    let (negative, numerator, denominator) = signed_value(number, from_radix);
+    if to_radix > 0 {
+        return convert_rational(
+            negative,
+            &numerator,
+            &denominator,
+            to_radix as u32,
+            precision,
+            rounding,
+        );
+    }
+
+    negabase_rational(
+        negative,
+        &numerator,
+        &denominator,
+        to_radix.unsigned_abs(),
+        precision,
+        rounding,
+    )
+}
+
+/// Writes the signed fraction `numerator / denominator` in base `-b` with at
+/// most `precision` fraction digits.
+pub(crate) fn negabase_rational(
+    negative: bool,
+    numerator: &BigUint,
+    denominator: &BigUint,
+    b: u32,
+    precision: usize,
+    rounding: RoundingMode,
+) -> Number {
+    let magnitude = round_scaled(numerator, denominator, b, precision, rounding, |m| {
+        m.clone().divrem_small(b)
+    });
+
+    // value = m / b^p = (m * (-1)^p) / (-b)^p
+    let negative = (negative != (precision % 2 == 1)) && !magnitude.is_zero();
+    let digits = to_negabase_integer((negative, magnitude), b);
+    let (integer, fractional) = place_point(digits, precision);
+    Number::new(integer, fractional)
+}
+
+/// Expands a value read in `from_radix`, which may be negative, exactly in
+/// the positive `to_base`, marking any repetend.
+pub fn expand_signed_radix(
+    number: &Number,
+    from_radix: i32,
+    to_base: u32,
+    max_digits: usize,
+) -> Expansion {
+    let (negative, numerator, denominator) = signed_value(number, from_radix);
+    expand_rational(negative, &numerator, &denominator, to_base, max_digits)
+}
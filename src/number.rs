@@ -0,0 +1,86 @@
+use crate::bigint::BigUint;
+
+/// A signed positional number split into its digit groups, most significant first.
+///
+/// `repetend` holds the digits that repeat forever after `fractional`, so
+/// `0.1(6)` is `fractional: [1], repetend: [6]`.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Number {
+    pub negative: bool,
+    pub integer: Vec<u32>,
+    pub fractional: Vec<u32>,
+    pub repetend: Vec<u32>,
+}
+
+impl Number {
+    pub fn new(integer: Vec<u32>, fractional: Vec<u32>) -> Self {
+        Number {
+            negative: false,
+            integer,
+            fractional,
+            repetend: Vec::new(),
+        }
+    }
+
+    pub fn negated(mut self) -> Self {
+        self.negative = !self.negative;
+        self
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.integer
+            .iter()
+            .chain(&self.fractional)
+            .chain(&self.repetend)
+            .all(|&digit| digit == 0)
+    }
+
+    /// The magnitude read in `base`, as long as every fraction and repetend
+    /// digit is zero.
+    pub fn integer_value(&self, base: u32) -> Result<BigUint, String> {
+        if self
+            .fractional
+            .iter()
+            .chain(&self.repetend)
+            .any(|&digit| digit != 0)
+        {
+            return Err("Expected an integer value".to_string());
+        }
+        Ok(BigUint::from_digits(&self.integer, base))
+    }
+
+    /// Moves the radix point `places` digits to the right (left when
+    /// negative), drawing on the repetend once the written fraction runs out.
+    pub fn shifted(mut self, places: i64) -> Self {
+        if places > 0 {
+            let places = places as usize;
+            let taken = places.min(self.fractional.len());
+            self.integer.extend(self.fractional.drain(..taken));
+
+            let remaining = places - taken;
+            if self.repetend.is_empty() {
+                self.integer.extend(std::iter::repeat_n(0, remaining));
+            } else {
+                let period = self.repetend.len();
+                self.integer
+                    .extend((0..remaining).map(|i| self.repetend[i % period]));
+                self.repetend.rotate_left(remaining % period);
+            }
+        } else if places < 0 {
+            let places = places.unsigned_abs() as usize;
+            if self.integer.len() < places {
+                let padding = places - self.integer.len();
+                self.integer.splice(0..0, std::iter::repeat_n(0, padding));
+            }
+            let moved = self.integer.split_off(self.integer.len() - places);
+            self.fractional.splice(0..0, moved);
+        }
+
+        let leading_zeros = self.integer.iter().take_while(|&&digit| digit == 0).count();
+        self.integer.drain(..leading_zeros);
+        if self.integer.is_empty() {
+            self.integer.push(0);
+        }
+        self
+    }
+}
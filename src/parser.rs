@@ -1,9 +1,15 @@
-fn char_to_digit(c: char, base: u32) -> Result<u32, String> {
-    let digit = if c.is_ascii_digit() {
-        c.to_digit(10).unwrap()
-    } else if c.is_ascii_alphabetic() {
-        c.to_ascii_uppercase() as u32 - 'A' as u32 + 10
-    } else {
+use crate::alphabet::Alphabet;
+use crate::bigint::BigUint;
+use crate::number::Number;
+
+pub(crate) fn char_to_digit(c: char, base: u32, alphabet: &Alphabet) -> Result<u32, String> {
+    let Some(digit) = alphabet.digit_value(c) else {
+        if let Some(symbol) = alphabet.case_mismatch(c) {
+            return Err(format!(
+                "Ambiguous case: '{}' is not a digit of this case-sensitive alphabet, but '{}' is",
+                c, symbol
+            ));
+        }
         return Err(format!("Invalid character: '{}'", c));
     };
 
@@ -14,39 +20,368 @@ fn char_to_digit(c: char, base: u32) -> Result<u32, String> {
     Ok(digit)
 }
 
+pub(crate) fn parse_digits(
+    digits: &str,
+    base: u32,
+    alphabet: &Alphabet,
+) -> Result<Vec<u32>, String> {
+    digits
+        .chars()
+        .map(|c| char_to_digit(c, base, alphabet))
+        .collect()
+}
+
+fn split_repetend(fraction: &str) -> Result<(&str, Option<&str>), String> {
+    let Some(open) = fraction.find('(') else {
+        if fraction.contains(')') {
+            return Err("Unmatched ')' in repeating fraction".to_string());
+        }
+        return Ok((fraction, None));
+    };
+
+    let Some(repetend) = fraction[open + 1..].strip_suffix(')') else {
+        return Err("Repetend must be closed with ')' at the end of the number".to_string());
+    };
+    if repetend.is_empty() {
+        return Err("Empty repetend: '()' needs at least one digit".to_string());
+    }
+    if repetend.contains(['(', ')']) {
+        return Err("Only one parenthesized repetend is allowed".to_string());
+    }
+
+    Ok((&fraction[..open], Some(repetend)))
+}
+
+pub(crate) fn split_sign<'a>(value: &'a str, alphabet: &Alphabet) -> (&'a str, &'a str) {
+    match value.chars().next() {
+        Some(c) if (c == '-' || c == '+') && alphabet.digit_value(c).is_none() => value.split_at(1),
+        _ => ("", value),
+    }
+}
+
+const MAX_EXPONENT: i64 = 1_000_000;
+
+/// An exponent marker, lowercased, and the power it applies.
+type Exponent = (char, i64);
+
+/// Splits off a trailing exponent written in decimal after `e` (powers of
+/// ten), `p` (powers of two) or `@` (powers of the base). A marker that is a
+/// digit of `base` is read as a digit.
+fn split_exponent<'a>(
+    value: &'a str,
+    base: u32,
+    alphabet: &Alphabet,
+) -> Result<(&'a str, Option<Exponent>), String> {
+    let is_marker = |c: char| {
+        matches!(c, 'e' | 'E' | 'p' | 'P' | '@')
+            && alphabet.digit_value(c).is_none_or(|digit| digit >= base)
+    };
+    let Some(position) = value.find(is_marker) else {
+        return Ok((value, None));
+    };
+
+    let marker = value[position..].chars().next().unwrap();
+    let exponent = &value[position + marker.len_utf8()..];
+    let digits = exponent.strip_prefix(['-', '+']).unwrap_or(exponent);
+    if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return Err(format!("Invalid exponent '{}'", exponent));
+    }
+    let exponent = exponent
+        .parse::<i64>()
+        .ok()
+        .filter(|n| n.abs() <= MAX_EXPONENT)
+        .ok_or_else(|| format!("Exponent '{}' is too large", exponent))?;
+
+    Ok((
+        &value[..position],
+        Some((marker.to_ascii_lowercase(), exponent)),
+    ))
+}
+
+/// Scales `number` by the exponent found by [`split_exponent`].
+fn apply_exponent(
+    number: Number,
+    base: u32,
+    marker: char,
+    exponent: i64,
+) -> Result<Number, String> {
+    match marker {
+        'e' if base != 10 => Err(format!(
+            "An 'e' exponent means a power of ten and needs base 10, not base {}",
+            base
+        )),
+        'p' if !base.is_power_of_two() => Err(format!(
+            "A 'p' exponent means a power of two and needs a power-of-two base, not base {}",
+            base
+        )),
+        'p' => {
+            let bits_per_digit = base.trailing_zeros() as i64;
+            let remainder = exponent.rem_euclid(bits_per_digit) as u32;
+            let number = if remainder == 0 {
+                number
+            } else {
+                double_digits(number, base, remainder)?
+            };
+            Ok(number.shifted(exponent.div_euclid(bits_per_digit)))
+        }
+        _ => Ok(number.shifted(exponent)),
+    }
+}
+
+/// Multiplies a terminating `number` by `2^times`, keeping its base.
+fn double_digits(number: Number, base: u32, times: u32) -> Result<Number, String> {
+    if !number.repetend.is_empty() {
+        return Err(
+            "A 'p' exponent cannot scale a repeating fraction by a partial digit".to_string(),
+        );
+    }
+
+    let scale = number.fractional.len();
+    let all_digits = [number.integer.as_slice(), &number.fractional].concat();
+    let mut value = BigUint::from_digits(&all_digits, base);
+    value.mul_small(1 << times);
+
+    let mut digits = value.to_digits(base);
+    if digits.len() <= scale {
+        digits.splice(0..0, vec![0; scale + 1 - digits.len()]);
+    }
+    let fractional = digits.split_off(digits.len() - scale);
+    Ok(Number {
+        negative: number.negative,
+        ..Number::new(digits, fractional)
+    })
+}
+
+const DIGIT_SEPARATORS: [char; 3] = ['_', '\'', ' '];
+
+/// Removes `_`, `'` and space digit separators, each of which must sit between
+/// two digits. A separator that the alphabet uses as a digit is kept.
+pub(crate) fn strip_separators(value: &str, alphabet: &Alphabet) -> Result<String, String> {
+    let is_separator = |c: char| DIGIT_SEPARATORS.contains(&c) && alphabet.digit_value(c).is_none();
+    let is_digit_like =
+        |c: char| !is_separator(c) && (c.is_alphanumeric() || alphabet.digit_value(c).is_some());
+
+    let chars: Vec<char> = value.chars().collect();
+    let mut stripped = String::with_capacity(value.len());
+    for (i, &c) in chars.iter().enumerate() {
+        if !is_separator(c) {
+            stripped.push(c);
+            continue;
+        }
+        let between_digits = i > 0
+            && i + 1 < chars.len()
+            && is_digit_like(chars[i - 1])
+            && is_digit_like(chars[i + 1]);
+        if !between_digits {
+            return Err(format!(
+                "Digit separator '{}' must sit between two digits",
+                c
+            ));
+        }
+    }
+
+    Ok(stripped)
+}
+
+const RADIX_PREFIXES: [(&str, u32); 7] = [
+    ("0x", 16),
+    ("0b", 2),
+    ("0o", 8),
+    ("#x", 16),
+    ("#b", 2),
+    ("#o", 8),
+    ("#d", 10),
+];
+
+const RADIX_SUFFIXES: [(char, u32); 2] = [('h', 16), ('b', 2)];
+
+/// Finds a radix marker and returns its base, the bare digits and the marker
+/// as written.
+fn find_radix_marker<'a>(
+    body: &'a str,
+    is_digit: impl Fn(char) -> bool,
+    alphabet: &Alphabet,
+) -> Option<(u32, &'a str, String)> {
+    if let Some((radix, rest)) = body.split_once('#')
+        && !is_digit('#')
+        && !radix.is_empty()
+        && radix.chars().all(|c| c.is_ascii_digit())
+        && let Some(digits) = rest.strip_suffix('#')
+        && let Ok(base) = radix.parse::<u32>()
+    {
+        return Some((base, digits, format!("{}#...#", radix)));
+    }
+
+    for (prefix, base) in RADIX_PREFIXES {
+        let Some(head) = body.get(..2) else {
+            break;
+        };
+        let marker = head.chars().nth(1).unwrap();
+        if body.len() > 2 && head.eq_ignore_ascii_case(prefix) && !is_digit(marker) {
+            return Some((base, &body[2..], head.to_string()));
+        }
+    }
+
+    let last = body.chars().last()?;
+    for (suffix, base) in RADIX_SUFFIXES {
+        let digits = &body[..body.len() - last.len_utf8()];
+        let valid_in_base = |c: char| c == '.' || alphabet.digit_value(c).is_some_and(|d| d < base);
+        if last.eq_ignore_ascii_case(&suffix)
+            && !is_digit(last)
+            && !digits.is_empty()
+            && digits.chars().all(valid_in_base)
+        {
+            return Some((base, digits, last.to_string()));
+        }
+    }
+
+    None
+}
+
+/// Resolves the source base of a literal such as `0xFF`, `#b101`, `0FFh`,
+/// `1010b` or `16#FF#`, returning the base and the value without its marker.
+///
+/// A marker character that is already a digit of `base` is read as a digit,
+/// so `0b1` in base 16 stays the hex number `0B1`. A marker naming a base
+/// other than `base` is an error. Digit separators are removed from the
+/// returned value.
+pub fn detect_radix(
+    value: &str,
+    base: Option<u32>,
+    alphabet: &Alphabet,
+) -> Result<(u32, String), String> {
+    let value = strip_separators(value, alphabet)?;
+    let (sign, body) = split_sign(&value, alphabet);
+    let is_digit = |c: char| base.is_some_and(|b| alphabet.digit_value(c).is_some_and(|d| d < b));
+
+    match (find_radix_marker(body, is_digit, alphabet), base) {
+        (Some((detected, _, marker)), Some(given)) if detected != given => Err(format!(
+            "Radix marker '{}' means base {}, but the source base is {}",
+            marker, detected, given
+        )),
+        (Some((_, digits, marker)), _) if !split_sign(digits, alphabet).0.is_empty() => {
+            Err(format!(
+                "A sign goes before the radix marker '{}', not after it",
+                marker
+            ))
+        }
+        (Some((detected, digits, _)), _) => Ok((detected, format!("{}{}", sign, digits))),
+        (None, Some(given)) => Ok((given, value.clone())),
+        (None, None) => Err(
+            "No source base given and no radix prefix or suffix (such as 0x or h) found"
+                .to_string(),
+        ),
+    }
+}
+
+/// Parses a literal whose base may come from a radix marker instead of `base`.
+pub fn parse_literal(
+    value: &str,
+    base: Option<u32>,
+    alphabet: &Alphabet,
+) -> Result<(u32, Number), String> {
+    let (base, digits) = detect_radix(value, base, alphabet)?;
+    Ok((base, parse_number_with(&digits, base, alphabet)?))
+}
+
+/// Like [`parse_number_with`] for a base that may be negative. Base `-b`
+/// uses the digits of base `b`, and its `@` exponent counts powers of `-b`.
+pub fn parse_signed_radix(value: &str, radix: i32, alphabet: &Alphabet) -> Result<Number, String> {
+    let base = radix.unsigned_abs();
+    let max_base = alphabet.max_base();
+    if !(2..=max_base).contains(&base) {
+        return Err(format!(
+            "Base must be between -{} and -2 or between 2 and {}, got {}",
+            max_base, max_base, radix
+        ));
+    }
+
+    if radix < 0
+        && let (_, Some((marker @ ('e' | 'p'), _))) =
+            split_exponent(&strip_separators(value, alphabet)?, base, alphabet)?
+    {
+        return Err(format!(
+            "The '{}' exponent is not a power of the base in base {}; use '@'",
+            marker, radix
+        ));
+    }
+    parse_number_with(value, base, alphabet)
+}
+
+/// Reads plain digits with an optional radix point as the integer and
+/// fraction digits. Signs, separators, repetends and exponents, which
+/// [`parse_number_full`] accepts, are rejected.
 pub fn parse_number(value: &str, base: u32) -> Result<(Vec<u32>, Vec<u32>), String> {
-    if base < 2 || base > 36 {
-        return Err(format!("Base must be between 2 and 36, got {}", base));
+    // Reject other characters before parse_number_full reads them as syntax
+    let alphabet = Alphabet::standard();
+    if (2..=alphabet.max_base()).contains(&base) {
+        for c in value.chars().filter(|&c| c != '.') {
+            char_to_digit(c, base, &alphabet)?;
+        }
+    }
+    let number = parse_number_full(value, base)?;
+    Ok((number.integer, number.fractional))
+}
+
+pub fn parse_number_full(value: &str, base: u32) -> Result<Number, String> {
+    parse_number_with(value, base, &Alphabet::standard())
+}
+
+/// Like [`parse_number_full`], reading digits through `alphabet`. A leading `-` or
+/// `+` only counts as a sign when the alphabet does not use it as a digit.
+/// Digits may be grouped with `_`, `'` or spaces, as in `1111_0000`, and the
+/// value may end in an exponent: `1.5e-7`, `1.8p3` in base 16 or `FF@4`.
+pub fn parse_number_with(value: &str, base: u32, alphabet: &Alphabet) -> Result<Number, String> {
+    if !(2..=alphabet.max_base()).contains(&base) {
+        return Err(format!(
+            "Base must be between 2 and {}, got {}",
+            alphabet.max_base(),
+            base
+        ));
     }
 
+    let value = strip_separators(value, alphabet)?;
+    let (sign, value) = split_sign(&value, alphabet);
+    let negative = sign == "-";
+    let (value, exponent) = split_exponent(value, base, alphabet)?;
+
     let parts: Vec<&str> = value.split('.').collect();
     if parts.len() > 2 {
         return Err("Invalid number format: multiple decimal points".to_string());
     }
 
+    if parts[0].contains(['(', ')']) {
+        return Err("A repetend is only allowed after the radix point".to_string());
+    }
+
     let integer_part = if parts[0].is_empty() {
         if parts.len() == 1 {
             return Err("Empty number".to_string());
         }
         vec![0]
     } else {
-        let mut result = Vec::new();
-        for c in parts[0].chars() {
-            result.push(char_to_digit(c, base)?);
-        }
-        result
+        parse_digits(parts[0], base, alphabet)?
     };
 
-    let fractional_part = if parts.len() == 2 {
-        let mut result = Vec::new();
-        for c in parts[1].chars() {
-            result.push(char_to_digit(c, base)?);
-        }
-        result
+    let (fractional_part, repetend) = if parts.len() == 2 {
+        let (fraction, repetend) = split_repetend(parts[1])?;
+        let repetend = match repetend {
+            Some(digits) => parse_digits(digits, base, alphabet)?,
+            None => Vec::new(),
+        };
+        (parse_digits(fraction, base, alphabet)?, repetend)
     } else {
-        Vec::new()
+        (Vec::new(), Vec::new())
     };
 
-    Ok((integer_part, fractional_part))
+    let number = Number {
+        negative,
+        integer: integer_part,
+        fractional: fractional_part,
+        repetend,
+    };
+    match exponent {
+        Some((marker, exponent)) => apply_exponent(number, base, marker, exponent),
+        None => Ok(number),
+    }
 }
-
@@ -0,0 +1,132 @@
+use std::collections::BTreeMap;
+
+use crate::bigint::BigUint;
+use crate::number::Number;
+
+/// Base-φ digits keyed by the power of φ they count.
+type Powers = BTreeMap<i64, u32>;
+
+fn add_digit(powers: &mut Powers, power: i64, count: u32) {
+    *powers.entry(power).or_insert(0) += count;
+}
+
+fn take_digit(powers: &mut Powers, power: i64) {
+    let digit = powers.get_mut(&power).unwrap();
+    *digit -= 1;
+    if *digit == 0 {
+        powers.remove(&power);
+    }
+}
+
+/// Rewrites the digits into standard form, all 0 or 1 with no two adjacent
+/// 1s, using `2φ^k = φ^(k+1) + φ^(k-2)` and `φ^k + φ^(k+1) = φ^(k+2)`.
+fn normalize(powers: &mut Powers) {
+    loop {
+        let carry = powers.iter().rev().find_map(|(&power, &digit)| {
+            if digit >= 2 {
+                Some((power, true))
+            } else if powers.contains_key(&(power - 1)) {
+                Some((power - 1, false))
+            } else {
+                None
+            }
+        });
+
+        match carry {
+            None => return,
+            Some((power, true)) => {
+                take_digit(powers, power);
+                take_digit(powers, power);
+                add_digit(powers, power + 1, 1);
+                add_digit(powers, power - 2, 1);
+            }
+            Some((power, false)) => {
+                take_digit(powers, power);
+                take_digit(powers, power + 1);
+                add_digit(powers, power + 2, 1);
+            }
+        }
+    }
+}
+
+/// Writes a non-negative integer in base φ (the golden ratio) in standard
+/// form. Every integer has a finite expansion, though most need fraction
+/// digits: 2 is `10.01`.
+pub fn to_phinary(value: &BigUint) -> Number {
+    // Binary doubling: 2x = xφ + xφ^-2, adding 1 for each set bit
+    let mut powers = Powers::new();
+    for bit in value.to_digits(2) {
+        let doubled = std::mem::take(&mut powers);
+        for (&power, &digit) in &doubled {
+            add_digit(&mut powers, power + 1, digit);
+            add_digit(&mut powers, power - 2, digit);
+        }
+        if bit == 1 {
+            add_digit(&mut powers, 0, 1);
+        }
+        normalize(&mut powers);
+    }
+
+    let top = powers.keys().next_back().copied().unwrap_or(0).max(0);
+    let bottom = powers.keys().next().copied().unwrap_or(0).min(0);
+    let digit = |power: i64| powers.get(&power).copied().unwrap_or(0);
+    Number::new(
+        (0..=top).rev().map(digit).collect(),
+        (bottom..0).rev().map(digit).collect(),
+    )
+}
+
+/// Reads a base-φ numeral back to the integer it stands for, ignoring its
+/// sign. The digits must be in standard form, and the value an integer.
+pub fn from_phinary(number: &Number) -> Result<BigUint, String> {
+    if !number.repetend.is_empty() {
+        return Err("A base-φ numeral cannot repeat".to_string());
+    }
+    let digits = number.integer.iter().chain(&number.fractional);
+    if let Some(&digit) = digits.clone().find(|&&digit| digit > 1) {
+        return Err(format!("Base-φ digits are 0 or 1, found {}", digit));
+    }
+    let written: Vec<u32> = digits.copied().collect();
+    if written.windows(2).any(|pair| pair == [1, 1]) {
+        return Err("A standard base-φ numeral cannot have two adjacent 1s".to_string());
+    }
+
+    // φ^k = F(k)φ + F(k-1), and φ^-k = (-1)^k (F(k+1) - F(k)φ): collect the
+    // positive and negative parts of the rational and φ coefficients.
+    let places = number.integer.len().max(number.fractional.len() + 1) + 2;
+    let mut fibonacci = vec![BigUint::zero(), BigUint::one()];
+    while fibonacci.len() < places {
+        let next = &fibonacci[fibonacci.len() - 1] + &fibonacci[fibonacci.len() - 2];
+        fibonacci.push(next);
+    }
+
+    let (mut rational, mut rational_negative) = (BigUint::zero(), BigUint::zero());
+    let (mut phi, mut phi_negative) = (BigUint::zero(), BigUint::zero());
+    for (i, &digit) in number.integer.iter().rev().enumerate() {
+        if digit == 1 && i == 0 {
+            rational = &rational + &BigUint::one();
+        } else if digit == 1 {
+            phi = &phi + &fibonacci[i];
+            rational = &rational + &fibonacci[i - 1];
+        }
+    }
+    for (i, &digit) in number.fractional.iter().enumerate() {
+        let k = i + 1;
+        if digit == 0 {
+            continue;
+        }
+        if k % 2 == 0 {
+            rational = &rational + &fibonacci[k + 1];
+            phi_negative = &phi_negative + &fibonacci[k];
+        } else {
+            rational_negative = &rational_negative + &fibonacci[k + 1];
+            phi = &phi + &fibonacci[k];
+        }
+    }
+
+    // Digits of 0 and 1 never sum to a negative value
+    if phi != phi_negative || rational < rational_negative {
+        return Err("This base-φ numeral is not an integer".to_string());
+    }
+    Ok(&rational - &rational_negative)
+}
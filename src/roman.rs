@@ -0,0 +1,125 @@
+use crate::bigint::BigUint;
+
+/// The largest value with a Roman numeral: vinculum (overline) numerals
+/// count thousands up to 3999, and three more thousands-free places follow.
+pub const MAX_ROMAN: u64 = 3_999_999;
+
+/// The combining overline that marks a vinculum: `V̅` is 5000.
+const VINCULUM: char = '\u{0305}';
+
+const NUMERALS: [(u64, &str); 13] = [
+    (1000, "M"),
+    (900, "CM"),
+    (500, "D"),
+    (400, "CD"),
+    (100, "C"),
+    (90, "XC"),
+    (50, "L"),
+    (40, "XL"),
+    (10, "X"),
+    (9, "IX"),
+    (5, "V"),
+    (4, "IV"),
+    (1, "I"),
+];
+
+fn symbol_value(symbol: char) -> Option<u64> {
+    match symbol.to_ascii_uppercase() {
+        'I' => Some(1),
+        'V' => Some(5),
+        'X' => Some(10),
+        'L' => Some(50),
+        'C' => Some(100),
+        'D' => Some(500),
+        'M' => Some(1000),
+        _ => None,
+    }
+}
+
+fn plain_numeral(mut value: u64) -> String {
+    let mut numeral = String::new();
+    for (weight, symbols) in NUMERALS {
+        while value >= weight {
+            numeral.push_str(symbols);
+            value -= weight;
+        }
+    }
+    numeral
+}
+
+/// Writes `value` as a Roman numeral in canonical subtractive form. Values
+/// from 4000 put the thousands under a vinculum, so 4000 is `I̅V̅` and
+/// 1,000,000 is `M̅`; zero is `N` (nulla).
+pub fn to_roman(value: &BigUint) -> Result<String, String> {
+    let value = match value.to_u128() {
+        Some(value) if value <= MAX_ROMAN as u128 => value as u64,
+        _ => {
+            return Err(format!(
+                "Roman numerals only go up to {} with a vinculum",
+                MAX_ROMAN
+            ));
+        }
+    };
+    if value == 0 {
+        return Ok("N".to_string());
+    }
+    if value < 4000 {
+        return Ok(plain_numeral(value));
+    }
+
+    let thousands: String = plain_numeral(value / 1000)
+        .chars()
+        .flat_map(|symbol| [symbol, VINCULUM])
+        .collect();
+    Ok(thousands + &plain_numeral(value % 1000))
+}
+
+/// Reads a Roman numeral, in either case. Strict mode only accepts the
+/// canonical form that [`to_roman`] writes, rejecting `IIII`, `VX` or `IM`;
+/// lenient mode sums the symbols, subtracting each one that precedes a
+/// larger one.
+pub fn from_roman(numeral: &str, lenient: bool) -> Result<BigUint, String> {
+    let numeral = numeral.trim();
+    if numeral.eq_ignore_ascii_case("N") {
+        return Ok(BigUint::zero());
+    }
+    if numeral.is_empty() {
+        return Err("Empty Roman numeral".to_string());
+    }
+
+    let mut values = Vec::new();
+    let mut symbols = numeral.chars().peekable();
+    while let Some(symbol) = symbols.next() {
+        let value = symbol_value(symbol)
+            .ok_or_else(|| format!("Invalid Roman numeral symbol '{}'", symbol))?;
+        if symbols.next_if_eq(&VINCULUM).is_some() {
+            values.push(value * 1000);
+        } else {
+            values.push(value);
+        }
+    }
+
+    let mut total = 0;
+    for (i, &value) in values.iter().enumerate() {
+        if values.get(i + 1).is_some_and(|&next| next > value) {
+            total -= value as i64;
+        } else {
+            total += value as i64;
+        }
+    }
+    if total <= 0 || total as u64 > MAX_ROMAN {
+        return Err(format!("'{}' is not a valid Roman numeral", numeral));
+    }
+
+    let value = BigUint::from_u64(total as u64);
+    if !lenient {
+        let canonical = to_roman(&value)?;
+        if canonical != numeral.to_uppercase() {
+            return Err(format!(
+                "'{}' is not in canonical form: {} is written {}",
+                numeral, total, canonical
+            ));
+        }
+    }
+    Ok(value)
+}
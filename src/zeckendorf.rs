@@ -0,0 +1,98 @@
+use crate::bigint::BigUint;
+
+/// The Fibonacci numbers `1, 2, 3, 5, 8, ...` (F2 onwards) up to `limit`, or
+/// the first `count` of them, whichever runs out first.
+fn fibonacci_weights(limit: Option<&BigUint>, count: usize) -> Vec<BigUint> {
+    let mut weights = Vec::new();
+    let (mut current, mut next) = (BigUint::one(), BigUint::from_u64(2));
+    while weights.len() < count && limit.is_none_or(|limit| current <= *limit) {
+        let following = &current + &next;
+        weights.push(current);
+        current = next;
+        next = following;
+    }
+    weights
+}
+
+/// Writes `value` as a sum of non-consecutive Fibonacci numbers, most
+/// significant digit first, where digit `i` from the right weighs F(i + 2).
+/// No two adjacent digits are both 1; zero is `[0]`.
+pub fn to_zeckendorf(value: &BigUint) -> Vec<u32> {
+    let weights = fibonacci_weights(Some(value), usize::MAX);
+    let mut rest = value.clone();
+    let mut digits = Vec::with_capacity(weights.len());
+    for weight in weights.iter().rev() {
+        if *weight <= rest {
+            rest = &rest - weight;
+            digits.push(1);
+        } else {
+            digits.push(0);
+        }
+    }
+
+    if digits.is_empty() {
+        digits.push(0);
+    }
+    digits
+}
+
+/// Reads a Zeckendorf representation back, rejecting digits other than 0
+/// and 1 and adjacent ones.
+pub fn from_zeckendorf(digits: &[u32]) -> Result<BigUint, String> {
+    if let Some(&digit) = digits.iter().find(|&&digit| digit > 1) {
+        return Err(format!("Zeckendorf digits are 0 or 1, found {}", digit));
+    }
+    if digits.windows(2).any(|pair| pair == [1, 1]) {
+        return Err("A Zeckendorf representation cannot have two adjacent 1s".to_string());
+    }
+
+    let weights = fibonacci_weights(None, digits.len());
+    let mut value = BigUint::zero();
+    for (digit, weight) in digits.iter().rev().zip(&weights) {
+        if *digit == 1 {
+            value = &value + weight;
+        }
+    }
+    Ok(value)
+}
+
+/// The Fibonacci codeword of a positive integer: its Zeckendorf digits
+/// least significant first, followed by a terminating 1, so every codeword
+/// ends in `11`.
+pub fn fibonacci_encode(value: &BigUint) -> Result<Vec<u32>, String> {
+    if value.is_zero() {
+        return Err("Fibonacci coding only covers positive integers".to_string());
+    }
+    let mut code = to_zeckendorf(value);
+    code.reverse();
+    code.push(1);
+    Ok(code)
+}
+
+/// Splits a stream of concatenated Fibonacci codewords at each `11` and
+/// decodes every codeword.
+pub fn fibonacci_decode(bits: &[u32]) -> Result<Vec<BigUint>, String> {
+    if let Some(&bit) = bits.iter().find(|&&bit| bit > 1) {
+        return Err(format!("Fibonacci code bits are 0 or 1, found {}", bit));
+    }
+
+    let mut values = Vec::new();
+    let mut codeword: Vec<u32> = Vec::new();
+    for &bit in bits {
+        if bit == 1 && codeword.last() == Some(&1) {
+            codeword.reverse();
+            values.push(from_zeckendorf(&codeword)?);
+            codeword.clear();
+        } else {
+            codeword.push(bit);
+        }
+    }
+
+    if !codeword.is_empty() {
+        return Err("Fibonacci code ends without a terminating 11".to_string());
+    }
+    if values.is_empty() {
+        return Err("Empty Fibonacci code".to_string());
+    }
+    Ok(values)
+}
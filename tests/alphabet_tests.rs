@@ -0,0 +1,100 @@
+use base_converter::alphabet::Alphabet;
+
+#[test]
+fn test_standard_alphabet() {
+    let alphabet = Alphabet::standard();
+    assert_eq!(alphabet.max_base(), 36);
+    assert_eq!(alphabet.digit_value('7'), Some(7));
+    assert_eq!(alphabet.digit_value('F'), Some(15));
+    assert_eq!(alphabet.digit_value('f'), Some(15));
+    assert_eq!(alphabet.digit_value('@'), None);
+    assert_eq!(alphabet.symbol(35), 'Z');
+    assert_eq!(Alphabet::default(), alphabet);
+}
+
+#[test]
+fn test_presets() {
+    let base58 = Alphabet::base58();
+    assert_eq!(base58.max_base(), 58);
+    assert_eq!(base58.digit_value('1'), Some(0));
+    assert_eq!(base58.digit_value('0'), None);
+    assert_eq!(base58.digit_value('O'), None);
+    assert_eq!(base58.digit_value('I'), None);
+    assert_eq!(base58.digit_value('l'), None);
+    assert_eq!(base58.symbol(57), 'z');
+
+    let base62 = Alphabet::base62();
+    assert_eq!(base62.max_base(), 62);
+    assert_eq!(base62.digit_value('A'), Some(10));
+    assert_eq!(base62.digit_value('a'), Some(36));
+
+    let base64 = Alphabet::base64();
+    assert_eq!(base64.max_base(), 64);
+    assert_eq!(base64.digit_value('A'), Some(0));
+    assert_eq!(base64.digit_value('0'), Some(52));
+    assert_eq!(base64.digit_value('+'), Some(62));
+    assert_eq!(base64.digit_value('/'), Some(63));
+}
+
+#[test]
+fn test_from_spec() {
+    assert_eq!(Alphabet::from_spec("base58").unwrap(), Alphabet::base58());
+    assert_eq!(Alphabet::from_spec("Bitcoin").unwrap(), Alphabet::base58());
+    assert_eq!(Alphabet::from_spec("base62").unwrap(), Alphabet::base62());
+    assert_eq!(Alphabet::from_spec("base64").unwrap(), Alphabet::base64());
+    assert_eq!(
+        Alphabet::from_spec("standard").unwrap(),
+        Alphabet::standard()
+    );
+
+    let custom = Alphabet::from_spec("xyz").unwrap();
+    assert_eq!(custom.max_base(), 3);
+    assert_eq!(custom.digit_value('y'), Some(1));
+    assert_eq!(custom.digit_value('Y'), None);
+}
+
+#[test]
+fn test_invalid_alphabets() {
+    assert!(Alphabet::new("").is_err());
+    assert!(Alphabet::new("a").is_err());
+    assert!(Alphabet::new("abca").is_err());
+    assert!(Alphabet::new("ab.").is_err());
+    assert!(Alphabet::new("ab(").is_err());
+    assert!(Alphabet::new("a b").is_err());
+}
+
+#[test]
+fn test_unicode_alphabet_of_256_symbols() {
+    let symbols: String = (0..256u32)
+        .map(|i| char::from_u32(0x100 + i).unwrap())
+        .collect();
+    let alphabet = Alphabet::new(&symbols).unwrap();
+    assert_eq!(alphabet.max_base(), 256);
+    assert_eq!(alphabet.symbol(255), '\u{1FF}');
+    assert_eq!(alphabet.digit_value('\u{1FF}'), Some(255));
+}
+
+#[test]
+fn test_case_modes() {
+    assert!(!Alphabet::standard().is_case_sensitive());
+    assert!(Alphabet::base62().is_case_sensitive());
+
+    let strict = Alphabet::standard().case_sensitive();
+    assert_eq!(strict.digit_value('F'), Some(15));
+    assert_eq!(strict.digit_value('f'), None);
+    assert_eq!(strict.case_mismatch('f'), Some('F'));
+    assert_eq!(strict.case_mismatch('F'), None);
+    assert_eq!(strict.case_mismatch('@'), None);
+
+    let folded = Alphabet::new("xyz").unwrap().case_insensitive().unwrap();
+    assert_eq!(folded.digit_value('Y'), Some(1));
+    assert_eq!(folded.case_mismatch('Y'), None);
+}
+
+#[test]
+fn test_case_insensitive_rejects_ambiguous_alphabets() {
+    assert!(Alphabet::base62().case_insensitive().is_err());
+    assert!(Alphabet::base58().case_insensitive().is_err());
+    assert!(Alphabet::new("aA").unwrap().case_insensitive().is_err());
+    assert!(Alphabet::standard().case_insensitive().is_ok());
+}
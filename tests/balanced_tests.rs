@@ -0,0 +1,115 @@
+use base_converter::alphabet::Alphabet;
+use base_converter::balanced::{
+    SignedDigits, balanced_alphabet, format_balanced, from_balanced, parse_balanced, to_balanced,
+};
+use base_converter::converter::{RoundingMode, convert_number};
+use base_converter::formatter::{FormatOptions, format_number};
+use base_converter::number::Number;
+use base_converter::parser::parse_number_full;
+
+fn to_text(value: &str, base: u32, precision: usize, rounding: RoundingMode) -> String {
+    let number = parse_number_full(value, 10).unwrap();
+    let digits = to_balanced(&number, 10, base, precision, rounding);
+    let options = FormatOptions {
+        alphabet: balanced_alphabet(base).unwrap(),
+        ..FormatOptions::default()
+    };
+    format_balanced(&digits, base, &options)
+}
+
+fn to_decimal(value: &str, base: u32) -> String {
+    let digits = parse_balanced(value, base, &balanced_alphabet(base).unwrap()).unwrap();
+    let number = from_balanced(&digits, base);
+    format_number(&convert_number(
+        &number,
+        base,
+        10,
+        10,
+        RoundingMode::TowardZero,
+    ))
+}
+
+#[test]
+fn test_balanced_ternary_integers() {
+    let text = |value| to_text(value, 3, 0, RoundingMode::TowardZero);
+    assert_eq!(text("0"), "0");
+    assert_eq!(text("1"), "1");
+    assert_eq!(text("2"), "1T");
+    assert_eq!(text("8"), "10T");
+    assert_eq!(text("-8"), "T01");
+    assert_eq!(text("13"), "111");
+    assert_eq!(text("-0"), "0");
+}
+
+#[test]
+fn test_balanced_ternary_fractions() {
+    assert_eq!(to_text("0.25", 3, 6, RoundingMode::TowardZero), "0.1T1T1T");
+    assert_eq!(to_text("0.5", 3, 4, RoundingMode::TowardZero), "0.1111");
+    assert_eq!(to_text("-2.5", 3, 3, RoundingMode::TowardZero), "T1.TTT");
+    assert_eq!(to_text("1", 3, 5, RoundingMode::TowardZero), "1");
+    assert_eq!(to_decimal("1T.1", 3), "2.3333333333");
+    assert_eq!(to_decimal("0.T", 3), "-0.3333333333");
+}
+
+#[test]
+fn test_general_balanced_bases() {
+    assert_eq!(
+        balanced_alphabet(5).unwrap(),
+        Alphabet::new("YZ012").unwrap().case_insensitive().unwrap()
+    );
+    assert_eq!(to_text("100", 9, 0, RoundingMode::TowardZero), "121");
+    assert_eq!(to_text("-54", 5, 0, RoundingMode::TowardZero), "YZ1");
+    assert_eq!(to_text("12", 25, 0, RoundingMode::TowardZero), "C");
+    assert_eq!(to_text("13", 25, 0, RoundingMode::TowardZero), "1O");
+    assert_eq!(to_decimal("YZ1", 5), "-54");
+    assert_eq!(to_decimal("4.W", 9), "3.5555555555");
+}
+
+#[test]
+fn test_balanced_roundtrip() {
+    for base in [3, 5, 7, 11] {
+        for value in -200..=200 {
+            let text = to_text(&value.to_string(), base, 0, RoundingMode::TowardZero);
+            assert_eq!(to_decimal(&text, base), value.to_string(), "base {}", base);
+        }
+    }
+}
+
+#[test]
+fn test_balanced_half_even_ties() {
+    let text = |value, base| to_text(value, base, 0, RoundingMode::HalfEven);
+    // 2 is 1T and 3 is 10 in balanced ternary, so the tie goes up to 3
+    assert_eq!(text("2.5", 3), "10");
+    assert_eq!(text("2.5", 5), "2");
+    assert_eq!(text("3.5", 5), "1Y");
+    assert_eq!(text("-3.5", 5), "Z2");
+    assert_eq!(to_text("0.8(3)", 3, 1, RoundingMode::HalfEven), "1");
+}
+
+#[test]
+fn test_signed_digits() {
+    let digits = parse_balanced("-1T.T", 3, &balanced_alphabet(3).unwrap()).unwrap();
+    assert_eq!(digits, SignedDigits::new(vec![-1, 1], vec![1]));
+    assert_eq!(
+        from_balanced(&digits, 3),
+        Number::new(vec![1], vec![2]).negated()
+    );
+    assert!(
+        parse_balanced("0.00", 3, &balanced_alphabet(3).unwrap())
+            .unwrap()
+            .is_zero()
+    );
+}
+
+#[test]
+fn test_balanced_errors() {
+    assert!(balanced_alphabet(4).is_err());
+    assert!(balanced_alphabet(1).is_err());
+    assert!(balanced_alphabet(29).is_err());
+    let ternary = balanced_alphabet(3).unwrap();
+    assert!(parse_balanced("12", 3, &ternary).is_err());
+    assert!(parse_balanced("1.T.1", 3, &ternary).is_err());
+    assert!(parse_balanced("", 3, &ternary).is_err());
+    assert!(parse_balanced("10", 5, &ternary).is_err());
+    assert!(parse_balanced("10", 4, &Alphabet::standard()).is_err());
+}
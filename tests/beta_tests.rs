@@ -0,0 +1,115 @@
+use base_converter::alphabet::Alphabet;
+use base_converter::beta::{RealRadix, beta_expand, beta_to_positional, beta_value, parse_beta};
+use base_converter::bigint::BigUint;
+use base_converter::converter::{RoundingMode, convert_number};
+use base_converter::formatter::format_number;
+use base_converter::parser::parse_number_full;
+
+fn expand(value: &str, radix: &str, precision: usize) -> (String, bool) {
+    let radix = RealRadix::parse(radix).unwrap();
+    let expansion = beta_expand(
+        &parse_number_full(value, 10).unwrap(),
+        10,
+        &radix,
+        precision,
+    );
+    (format_number(&expansion.digits), expansion.exact)
+}
+
+fn value(digits: &str, radix: &str) -> (u128, u128) {
+    let radix = RealRadix::parse(radix).unwrap();
+    let number = parse_beta(digits, &radix, &Alphabet::standard()).unwrap();
+    let (numerator, denominator) = beta_value(&number, &radix);
+    (numerator.to_u128().unwrap(), denominator.to_u128().unwrap())
+}
+
+#[test]
+fn test_parse_radix() {
+    let radix = RealRadix::parse("6/4").unwrap();
+    assert_eq!(radix.numerator, BigUint::from_u64(3));
+    assert_eq!(radix.denominator, BigUint::from_u64(2));
+    assert!(radix.exact);
+    assert_eq!(radix.digit_count(), 2);
+    assert_eq!(
+        RealRadix::parse("1.5").unwrap().numerator,
+        BigUint::from_u64(3)
+    );
+    assert!(RealRadix::parse("sqrt9").unwrap().exact);
+    assert!(!RealRadix::parse("sqrt(2)").unwrap().exact);
+    let widest = RealRadix::parse("sqrt4294967295").unwrap();
+    assert!(!widest.exact);
+    assert_eq!(widest.digit_count(), 65_536);
+    assert_eq!(RealRadix::parse("e").unwrap().digit_count(), 3);
+    assert_eq!(RealRadix::parse("pi").unwrap().digit_count(), 4);
+    assert!(RealRadix::parse("1").is_err());
+    assert!(RealRadix::parse("2/3").is_err());
+    assert!(RealRadix::parse("3/0").is_err());
+    assert!(RealRadix::parse("x").is_err());
+}
+
+#[test]
+fn test_integer_radix_matches_positional() {
+    assert_eq!(expand("255", "16", 10), ("FF".to_string(), true));
+    assert_eq!(expand("0.5", "2", 10), ("0.1".to_string(), true));
+    assert_eq!(expand("0.1", "2", 4), ("0.0001".to_string(), false));
+}
+
+#[test]
+fn test_rational_radix() {
+    assert_eq!(expand("0", "3/2", 10), ("0".to_string(), true));
+    assert_eq!(expand("1", "3/2", 10), ("1".to_string(), true));
+    // 3/2 + 4/9 is not 2, so the greedy digits run on
+    assert_eq!(expand("2", "3/2", 4), ("10.01".to_string(), false));
+    assert_eq!(expand("3", "10/3", 10), ("3".to_string(), true));
+    assert_eq!(expand("-2.25", "3/2", 10), ("-100".to_string(), true));
+}
+
+#[test]
+fn test_real_radix_truncation() {
+    assert_eq!(expand("0", "e", 10), ("0".to_string(), true));
+    assert_eq!(expand("1", "pi", 10), ("1".to_string(), true));
+    assert_eq!(expand("10", "e", 8), ("102.11201011".to_string(), false));
+    // Square roots and φ expand exactly, not from their approximations
+    assert_eq!(expand("2", "sqrt2", 10), ("100".to_string(), true));
+    assert_eq!(expand("4", "sqrt2", 6), ("10000".to_string(), true));
+    assert_eq!(expand("0.5", "sqrt2", 6), ("0.01".to_string(), true));
+    assert_eq!(
+        expand("0.3333", "sqrt2", 8),
+        ("0.00010001".to_string(), false)
+    );
+    assert_eq!(expand("10", "phi", 4), ("10100.0101".to_string(), true));
+    assert_eq!(expand("-7", "phi", 4), ("-10000.0001".to_string(), true));
+    assert!(RealRadix::parse("pi").unwrap().approximated());
+    assert!(!RealRadix::parse("sqrt2").unwrap().approximated());
+}
+
+#[test]
+fn test_beta_value() {
+    // 1 + 2/3 and (3/2)^4 + (2/3)^2
+    assert_eq!(value("1.1", "3/2"), (5, 3));
+    assert_eq!(value("10000.01", "3/2"), (793, 144));
+    assert_eq!(value(".1", "3/2"), (2, 3));
+    let radix = RealRadix::parse("3/2").unwrap();
+    assert!(parse_beta("2", &radix, &Alphabet::standard()).is_err());
+    assert!(parse_beta("0.(1)", &radix, &Alphabet::standard()).is_err());
+}
+
+#[test]
+fn test_roundtrip_through_positional() {
+    let radix = RealRadix::parse("3/2").unwrap();
+    for value in ["7", "0.75", "-12.5", "100"] {
+        let number = parse_number_full(value, 10).unwrap();
+        let expansion = beta_expand(&number, 10, &radix, 60);
+        let (base, back) = beta_to_positional(&expansion.digits, &radix, 10);
+        assert_eq!(base, 6);
+        let decimal = convert_number(&back, base, 10, 8, RoundingMode::HalfUp);
+        let expected = parse_number_full(value, 10).unwrap();
+        let decimal = format_number(&decimal);
+        assert!(
+            decimal.starts_with(&format_number(&expected)),
+            "{} -> {}",
+            value,
+            decimal
+        );
+    }
+}
@@ -0,0 +1,119 @@
+use base_converter::bigint::BigUint;
+
+#[test]
+fn test_from_and_to_u128() {
+    assert_eq!(BigUint::from_u128(0).to_u128(), Some(0));
+    assert_eq!(BigUint::from_u128(u128::MAX).to_u128(), Some(u128::MAX));
+    assert!(BigUint::zero().is_zero());
+    assert_eq!(BigUint::from_u64(1), BigUint::one());
+}
+
+#[test]
+fn test_bits() {
+    assert_eq!(BigUint::zero().bits(), 0);
+    assert_eq!(BigUint::one().bits(), 1);
+    assert_eq!(BigUint::from_u64(255).bits(), 8);
+    assert_eq!(BigUint::pow(2, 200).bits(), 201);
+}
+
+#[test]
+fn test_arithmetic() {
+    let a = BigUint::from_u128(u128::MAX);
+    let b = BigUint::one();
+    let sum = &a + &b;
+    assert_eq!(sum, BigUint::pow(2, 128));
+    assert_eq!(&sum - &b, a);
+    assert_eq!(
+        &a * &a,
+        &(&BigUint::pow(2, 256) - &BigUint::pow(2, 129)) + &b
+    );
+}
+
+#[test]
+fn test_divrem() {
+    let dividend = BigUint::pow(10, 60);
+    let divisor = BigUint::pow(7, 20);
+    let (quotient, remainder) = dividend.divrem(&divisor);
+    assert!(remainder < divisor);
+    assert_eq!(&(&quotient * &divisor) + &remainder, dividend);
+
+    let (quotient, remainder) = BigUint::from_u64(5).divrem(&BigUint::pow(2, 100));
+    assert!(quotient.is_zero());
+    assert_eq!(remainder, BigUint::from_u64(5));
+}
+
+#[test]
+fn test_divrem_small() {
+    let mut n = BigUint::pow(10, 40);
+    assert_eq!(n.divrem_small(3), 1);
+    n.mul_small(3);
+    n.add_small(1);
+    assert_eq!(n, BigUint::pow(10, 40));
+}
+
+#[test]
+fn test_digits_roundtrip() {
+    let digits: Vec<u32> = (0..500).map(|i| (i * 7 + 3) % 36).skip(1).collect();
+    let n = BigUint::from_digits(&digits, 36);
+    assert_eq!(n.to_digits(36), digits);
+    assert_eq!(BigUint::zero().to_digits(16), vec![0]);
+}
+
+/// Pseudo-random digits from a linear congruential generator, with a nonzero
+/// leading digit.
+fn random_digits(count: usize, base: u32, seed: u64) -> Vec<u32> {
+    let mut state = seed;
+    let mut digits: Vec<u32> = (0..count)
+        .map(|_| {
+            state = state
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+            ((state >> 33) % base as u64) as u32
+        })
+        .collect();
+    digits[0] = digits[0].max(1);
+    digits
+}
+
+#[test]
+fn test_karatsuba_multiplication() {
+    // (10^n - 1)² = 10^2n - 2 10^n + 1, well past the Karatsuba threshold
+    for n in [400, 1000, 3001] {
+        let nines = &BigUint::pow(10, n) - &BigUint::one();
+        let expected = &(&BigUint::pow(10, 2 * n) - &BigUint::pow(10, n)) - &BigUint::pow(10, n);
+        assert_eq!(&nines * &nines, &expected + &BigUint::one());
+    }
+
+    // Unbalanced factors
+    let long = BigUint::from_digits(&random_digits(20_000, 10, 1), 10);
+    let short = BigUint::from_digits(&random_digits(700, 10, 2), 10);
+    let (quotient, remainder) = (&long * &short).divrem(&short);
+    assert_eq!(quotient, long);
+    assert!(remainder.is_zero());
+}
+
+#[test]
+fn test_divide_and_conquer_conversion() {
+    for (count, base, seed) in [
+        (5_000, 10, 3),
+        (12_345, 16, 4),
+        (3_000, 36, 5),
+        (40_000, 2, 6),
+    ] {
+        let digits = random_digits(count, base, seed);
+        let value = BigUint::from_digits(&digits, base);
+        assert_eq!(value, BigUint::from_digits_schoolbook(&digits, base));
+        assert_eq!(value.to_digits(base), digits);
+        for target in [2, 7, 10, 16, 36] {
+            assert_eq!(value.to_digits(target), value.to_digits_schoolbook(target));
+        }
+    }
+
+    // Runs of zeros must survive as padding in the low halves
+    let mut digits = vec![1];
+    digits.extend(vec![0; 12_000]);
+    digits.push(7);
+    let value = BigUint::from_digits(&digits, 10);
+    assert_eq!(value.to_digits(10), digits);
+    assert_eq!(BigUint::pow(10, 12_000).to_digits(10).len(), 12_001);
+}
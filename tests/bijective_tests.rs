@@ -0,0 +1,102 @@
+use base_converter::alphabet::Alphabet;
+use base_converter::bigint::BigUint;
+use base_converter::bijective::{
+    Bijective, MAX_TALLY, bijective_alphabet, format_bijective, parse_bijective,
+};
+use base_converter::formatter::FormatOptions;
+
+fn to_text(value: u128, base: u32) -> String {
+    let number = Bijective::from_integer(false, &BigUint::from_u128(value), base).unwrap();
+    let options = FormatOptions {
+        alphabet: bijective_alphabet(base).unwrap(),
+        ..FormatOptions::default()
+    };
+    format_bijective(&number, &options)
+}
+
+fn to_value(text: &str, base: u32) -> u128 {
+    parse_bijective(text, base, &bijective_alphabet(base).unwrap())
+        .unwrap()
+        .magnitude(base)
+        .to_u128()
+        .unwrap()
+}
+
+#[test]
+fn test_spreadsheet_columns() {
+    assert_eq!(to_text(1, 26), "A");
+    assert_eq!(to_text(26, 26), "Z");
+    assert_eq!(to_text(27, 26), "AA");
+    assert_eq!(to_text(52, 26), "AZ");
+    assert_eq!(to_text(702, 26), "ZZ");
+    assert_eq!(to_text(703, 26), "AAA");
+    assert_eq!(to_text(16384, 26), "XFD");
+    assert_eq!(to_value("XFD", 26), 16384);
+    assert_eq!(to_value("xfd", 26), 16384);
+    assert_eq!(to_value("AA", 26), 27);
+}
+
+#[test]
+fn test_bijective_base_ten() {
+    assert_eq!(to_text(10, 10), "A");
+    assert_eq!(to_text(20, 10), "1A");
+    assert_eq!(to_text(100, 10), "9A");
+    assert_eq!(to_text(111, 10), "111");
+    assert_eq!(to_value("9A", 10), 100);
+}
+
+#[test]
+fn test_bijective_tally() {
+    assert_eq!(to_text(0, 1), "");
+    assert_eq!(to_text(5, 1), "11111");
+    assert_eq!(to_value("1111", 1), 4);
+    assert!(parse_bijective("12", 1, &bijective_alphabet(1).unwrap()).is_err());
+    let too_long = BigUint::from_u64(MAX_TALLY as u64 + 1);
+    assert!(Bijective::from_integer(false, &too_long, 1).is_err());
+}
+
+#[test]
+fn test_bijective_zero_and_sign() {
+    assert_eq!(to_text(0, 26), "");
+    assert_eq!(to_value("", 26), 0);
+
+    let alphabet = bijective_alphabet(26).unwrap();
+    let negative = parse_bijective("-AB", 26, &alphabet).unwrap();
+    assert!(negative.negative);
+    assert_eq!(negative.digits, vec![1, 2]);
+    let options = FormatOptions {
+        alphabet,
+        ..FormatOptions::default()
+    };
+    assert_eq!(format_bijective(&negative, &options), "-AB");
+    assert!(
+        !parse_bijective("-", 26, &options.alphabet)
+            .unwrap()
+            .negative
+    );
+}
+
+#[test]
+fn test_bijective_roundtrip() {
+    for base in [1, 2, 3, 10, 26, 35] {
+        for value in 0..=1000 {
+            assert_eq!(
+                to_value(&to_text(value, base), base),
+                value,
+                "base {}",
+                base
+            );
+        }
+    }
+}
+
+#[test]
+fn test_bijective_errors() {
+    assert!(bijective_alphabet(0).is_err());
+    assert!(bijective_alphabet(36).is_err());
+    let columns = bijective_alphabet(26).unwrap();
+    assert!(parse_bijective("A0", 26, &columns).is_err());
+    assert!(parse_bijective("A.B", 26, &columns).is_err());
+    assert!(parse_bijective("AB", 40, &Alphabet::standard()).is_err());
+    assert!(parse_bijective("0", 10, &bijective_alphabet(10).unwrap()).is_err());
+}
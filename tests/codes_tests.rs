@@ -0,0 +1,110 @@
+use base_converter::bigint::BigUint;
+use base_converter::codes::{BinaryCode, decode_binary_code, encode_binary_code};
+
+fn bits(text: &str) -> Vec<u32> {
+    text.chars().map(|c| c.to_digit(2).unwrap()).collect()
+}
+
+fn encode(value: u64, code: BinaryCode) -> String {
+    encode_binary_code(&BigUint::from_u64(value), code)
+        .iter()
+        .map(|bit| bit.to_string())
+        .collect()
+}
+
+fn decode(text: &str, code: BinaryCode) -> Result<BigUint, String> {
+    decode_binary_code(&bits(text), code)
+}
+
+#[test]
+fn test_gray_code() {
+    let expected = ["0", "1", "11", "10", "110", "111", "101", "100"];
+    for (value, gray) in expected.iter().enumerate() {
+        assert_eq!(encode(value as u64, BinaryCode::Gray), *gray);
+    }
+    for value in 0..1024u64 {
+        let next = bits(&encode(value + 1, BinaryCode::Gray));
+        let mut current = bits(&encode(value, BinaryCode::Gray));
+        current.splice(0..0, vec![0; next.len() - current.len()]);
+        let changed = current.iter().zip(&next).filter(|(a, b)| a != b).count();
+        assert_eq!(changed, 1);
+        assert_eq!(
+            decode(&encode(value, BinaryCode::Gray), BinaryCode::Gray).unwrap(),
+            BigUint::from_u64(value)
+        );
+    }
+}
+
+#[test]
+fn test_bcd() {
+    assert_eq!(encode(1994, BinaryCode::PackedBcd), "0001100110010100");
+    assert_eq!(encode(0, BinaryCode::PackedBcd), "0000");
+    assert_eq!(encode(59, BinaryCode::UnpackedBcd), "0000010100001001");
+    assert_eq!(
+        decode("0001100110010100", BinaryCode::PackedBcd).unwrap(),
+        BigUint::from_u64(1994)
+    );
+    assert!(decode("00011010", BinaryCode::PackedBcd).is_err());
+    assert!(decode("000110", BinaryCode::PackedBcd).is_err());
+    assert!(decode("0001000000001001", BinaryCode::UnpackedBcd).is_err());
+}
+
+#[test]
+fn test_excess_3_and_2421() {
+    assert_eq!(encode(59, BinaryCode::Excess3), "10001100");
+    assert_eq!(encode(0, BinaryCode::Excess3), "0011");
+    assert!(decode("0010", BinaryCode::Excess3).is_err());
+    assert!(decode("1101", BinaryCode::Excess3).is_err());
+    assert_eq!(encode(59, BinaryCode::Aiken2421), "10111111");
+    assert_eq!(encode(4, BinaryCode::Aiken2421), "0100");
+    assert!(decode("0101", BinaryCode::Aiken2421).is_err());
+    // Both codes are self-complementing: inverting the bits gives 9 - d
+    for digit in 0..10u64 {
+        for code in [BinaryCode::Excess3, BinaryCode::Aiken2421] {
+            let inverted: String = encode(digit, code)
+                .chars()
+                .map(|bit| if bit == '0' { '1' } else { '0' })
+                .collect();
+            assert_eq!(
+                decode(&inverted, code).unwrap(),
+                BigUint::from_u64(9 - digit)
+            );
+        }
+    }
+}
+
+#[test]
+fn test_two_of_five() {
+    assert_eq!(encode(0, BinaryCode::TwoOfFive), "11000");
+    assert_eq!(encode(59, BinaryCode::TwoOfFive), "0101010100");
+    for digit in 0..10u64 {
+        let code = encode(digit, BinaryCode::TwoOfFive);
+        assert_eq!(code.matches('1').count(), 2);
+    }
+    assert!(decode("11100", BinaryCode::TwoOfFive).is_err());
+    assert!(decode("00001", BinaryCode::TwoOfFive).is_err());
+}
+
+#[test]
+fn test_roundtrip() {
+    let codes = [
+        BinaryCode::PackedBcd,
+        BinaryCode::UnpackedBcd,
+        BinaryCode::Excess3,
+        BinaryCode::TwoOfFive,
+        BinaryCode::Aiken2421,
+    ];
+    for code in codes {
+        for value in (0..2000u64).chain([u64::MAX]) {
+            let decoded = decode(&encode(value, code), code).unwrap();
+            assert_eq!(
+                decoded,
+                BigUint::from_u64(value),
+                "{} in {}",
+                value,
+                code.name()
+            );
+        }
+    }
+    assert!(decode("", BinaryCode::PackedBcd).is_err());
+}
@@ -0,0 +1,122 @@
+use base_converter::complement::{SignedEncoding, decode_signed, encode_signed};
+use base_converter::number::Number;
+
+fn integer(digits: Vec<u32>, negative: bool) -> Number {
+    Number {
+        negative,
+        ..Number::new(digits, vec![])
+    }
+}
+
+#[test]
+fn test_encode_twos_complement() {
+    let minus_42 = integer(vec![4, 2], true);
+    let pattern = encode_signed(&minus_42, 10, 8, SignedEncoding::TwosComplement).unwrap();
+    assert_eq!(pattern, vec![2, 1, 4]);
+
+    let minus_42_hex = integer(vec![2, 10], true);
+    let pattern = encode_signed(&minus_42_hex, 16, 8, SignedEncoding::TwosComplement).unwrap();
+    assert_eq!(pattern, vec![13, 6]);
+
+    let minus_one = integer(vec![1], true);
+    let pattern = encode_signed(&minus_one, 16, 32, SignedEncoding::TwosComplement).unwrap();
+    assert_eq!(pattern, vec![15; 8]);
+}
+
+#[test]
+fn test_encode_pads_to_full_width() {
+    let five = integer(vec![1, 0, 1], false);
+    let pattern = encode_signed(&five, 2, 8, SignedEncoding::TwosComplement).unwrap();
+    assert_eq!(pattern, vec![0, 0, 0, 0, 0, 1, 0, 1]);
+
+    let zero = integer(vec![0], false);
+    let pattern = encode_signed(&zero, 16, 12, SignedEncoding::TwosComplement).unwrap();
+    assert_eq!(pattern, vec![0, 0, 0]);
+}
+
+#[test]
+fn test_encode_ones_complement_and_sign_magnitude() {
+    let minus_5 = integer(vec![1, 0, 1], true);
+    let pattern = encode_signed(&minus_5, 2, 8, SignedEncoding::OnesComplement).unwrap();
+    assert_eq!(pattern, vec![1, 1, 1, 1, 1, 0, 1, 0]);
+
+    let pattern = encode_signed(&minus_5, 2, 8, SignedEncoding::SignMagnitude).unwrap();
+    assert_eq!(pattern, vec![1, 0, 0, 0, 0, 1, 0, 1]);
+}
+
+#[test]
+fn test_encode_overflow() {
+    let minus_128 = integer(vec![1, 2, 8], true);
+    assert!(encode_signed(&minus_128, 10, 8, SignedEncoding::TwosComplement).is_ok());
+    assert!(encode_signed(&minus_128, 10, 8, SignedEncoding::OnesComplement).is_err());
+    assert!(encode_signed(&minus_128, 10, 8, SignedEncoding::SignMagnitude).is_err());
+
+    let plus_128 = integer(vec![1, 2, 8], false);
+    assert!(encode_signed(&plus_128, 10, 8, SignedEncoding::TwosComplement).is_err());
+
+    let plus_127 = integer(vec![1, 2, 7], false);
+    assert!(encode_signed(&plus_127, 10, 8, SignedEncoding::TwosComplement).is_ok());
+
+    assert!(encode_signed(&plus_127, 10, 0, SignedEncoding::TwosComplement).is_err());
+    assert!(encode_signed(&plus_127, 10, 4_000_000_000, SignedEncoding::TwosComplement).is_err());
+}
+
+#[test]
+fn test_encode_rejects_fractions() {
+    let half = Number::new(vec![0], vec![5]);
+    assert!(encode_signed(&half, 10, 8, SignedEncoding::TwosComplement).is_err());
+
+    let whole = Number::new(vec![3], vec![0, 0]);
+    assert!(encode_signed(&whole, 10, 8, SignedEncoding::TwosComplement).is_ok());
+}
+
+#[test]
+fn test_decode_signed() {
+    let value = decode_signed(&[13, 6], 16, 8, SignedEncoding::TwosComplement).unwrap();
+    assert_eq!(value, integer(vec![2, 10], true));
+
+    let value = decode_signed(&[7, 15], 16, 8, SignedEncoding::TwosComplement).unwrap();
+    assert_eq!(value, integer(vec![7, 15], false));
+
+    let value = decode_signed(&[15, 10], 16, 8, SignedEncoding::OnesComplement).unwrap();
+    assert_eq!(value, integer(vec![5], true));
+
+    let value = decode_signed(&[8, 5], 16, 8, SignedEncoding::SignMagnitude).unwrap();
+    assert_eq!(value, integer(vec![5], true));
+}
+
+#[test]
+fn test_decode_negative_zero_patterns() {
+    let value = decode_signed(&[15, 15], 16, 8, SignedEncoding::OnesComplement).unwrap();
+    assert_eq!(value, integer(vec![0], false));
+
+    let value = decode_signed(&[8, 0], 16, 8, SignedEncoding::SignMagnitude).unwrap();
+    assert_eq!(value, integer(vec![0], false));
+}
+
+#[test]
+fn test_decode_overflow() {
+    assert!(decode_signed(&[1, 0, 0], 16, 8, SignedEncoding::TwosComplement).is_err());
+    assert!(
+        decode_signed(
+            &[1, 1, 1, 1, 1, 1, 1, 1, 1],
+            2,
+            8,
+            SignedEncoding::TwosComplement
+        )
+        .is_err()
+    );
+    assert!(decode_signed(&[1], 2, 4_000_000_000, SignedEncoding::TwosComplement).is_err());
+}
+
+#[test]
+fn test_roundtrip_wide_patterns() {
+    let digits: Vec<u32> = "170141183460469231731687303715884105728"
+        .chars()
+        .map(|c| c.to_digit(10).unwrap())
+        .collect();
+    let min_i128 = integer(digits, true);
+    let pattern = encode_signed(&min_i128, 10, 128, SignedEncoding::TwosComplement).unwrap();
+    let back = decode_signed(&pattern, 10, 128, SignedEncoding::TwosComplement).unwrap();
+    assert_eq!(back, min_i128);
+}
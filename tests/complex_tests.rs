@@ -0,0 +1,116 @@
+use base_converter::complex::{
+    ComplexBase, format_complex, from_complex_base, parse_complex, to_complex_base,
+};
+use base_converter::converter::RoundingMode;
+use base_converter::formatter::format_number;
+use base_converter::parser::parse_number_full;
+
+fn to_base(value: &str, base: &str, precision: usize) -> String {
+    let base = ComplexBase::parse(base).unwrap();
+    let value = parse_complex(value).unwrap();
+    format_number(&to_complex_base(
+        &value,
+        &base,
+        precision,
+        RoundingMode::HalfUp,
+    ))
+}
+
+fn from_base(digits: &str, base: &str) -> String {
+    let base = ComplexBase::parse(base).unwrap();
+    let number = parse_number_full(digits, base.digit_count()).unwrap();
+    format_complex(&from_complex_base(&number, &base, 10, RoundingMode::HalfUp))
+}
+
+#[test]
+fn test_parse_and_format_complex() {
+    for (input, output) in [
+        ("3+4i", "3+4i"),
+        ("3 - 4i", "3-4i"),
+        ("-2.5i", "-2.5i"),
+        ("i", "i"),
+        ("-i", "-i"),
+        ("7", "7"),
+        ("-1+0i", "-1"),
+        ("0.5-i", "0.5-i"),
+    ] {
+        assert_eq!(format_complex(&parse_complex(input).unwrap()), output);
+    }
+    assert!(parse_complex("3+4j").is_err());
+    assert!(parse_complex("3+xi").is_err());
+}
+
+#[test]
+fn test_parse_complex_base() {
+    assert_eq!(
+        ComplexBase::parse("-1+i"),
+        Ok(ComplexBase::NegativePlusI(1))
+    );
+    assert_eq!(ComplexBase::parse("2i"), Ok(ComplexBase::Imaginary(2)));
+    assert_eq!(ComplexBase::parse("-2+i").unwrap().digit_count(), 5);
+    assert_eq!(ComplexBase::parse("2i").unwrap().digit_count(), 4);
+    assert!(ComplexBase::parse("1i").is_err());
+    assert!(ComplexBase::parse("-0+i").is_err());
+    assert!(ComplexBase::parse("3").is_err());
+    assert!(ComplexBase::parse("70000i").is_err());
+    assert!(ComplexBase::parse("-70000+i").is_err());
+    assert_eq!(ComplexBase::parse("256i").unwrap().digit_count(), 65_536);
+    assert!(ComplexBase::parse("-256+i").is_err());
+}
+
+#[test]
+fn test_minus_one_plus_i() {
+    assert_eq!(to_base("0", "-1+i", 10), "0");
+    assert_eq!(to_base("2", "-1+i", 10), "1100");
+    assert_eq!(to_base("i", "-1+i", 10), "11");
+    assert_eq!(to_base("-i", "-1+i", 10), "111");
+    assert_eq!(to_base("-1", "-1+i", 10), "11101");
+    assert_eq!(from_base("1100", "-1+i"), "2");
+    assert_eq!(from_base("11101", "-1+i"), "-1");
+    assert_eq!(from_base("1.1", "-1+i"), "0.5-0.5i");
+}
+
+#[test]
+fn test_quater_imaginary() {
+    assert_eq!(to_base("4", "2i", 10), "10300");
+    assert_eq!(to_base("-2i", "2i", 10), "1030");
+    assert_eq!(to_base("i", "2i", 10), "10.2");
+    assert_eq!(to_base("3+4i", "2i", 10), "23");
+    assert_eq!(from_base("10300", "2i"), "4");
+    assert_eq!(from_base("10.2", "2i"), "i");
+    assert_eq!(from_base("0.1", "2i"), "-0.5i");
+}
+
+#[test]
+fn test_gaussian_integer_roundtrip() {
+    for base in ["-1+i", "-2+i", "2i", "3i"] {
+        for real in -12..=12 {
+            for imaginary in -12..=12 {
+                let value = format!("{}{:+}i", real, imaginary);
+                let digits = to_base(&value, base, 4);
+                let expected = format_complex(&parse_complex(&value).unwrap());
+                assert_eq!(
+                    from_base(&digits, base),
+                    expected,
+                    "{} in base {}",
+                    value,
+                    base
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_fractions_round_to_precision() {
+    // 0.5 = 1 - 2/4, since (2i)^-2 = -1/4
+    assert_eq!(to_base("0.5", "2i", 4), "1.02");
+    assert_eq!(
+        from_base(&to_base("0.25+0.75i", "-1+i", 8), "-1+i"),
+        "0.25+0.75i"
+    );
+    // A tenth has no finite expansion, so the scaled value is rounded
+    let digits = to_base("0.3333333333", "-1+i", 20);
+    let back = from_base(&digits, "-1+i");
+    assert!(back.starts_with("0.333"), "{}", back);
+}
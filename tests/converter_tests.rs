@@ -1,4 +1,10 @@
 use base_converter::converter::{convert_fractional_part, convert_integer_part};
+use base_converter::bigint::BigUint;
+use base_converter::converter::{
+    FractionalExpansion, RoundingMode, Scientific, convert_number, convert_scientific,
+    expand_fractional_part,
+};
+use base_converter::number::Number;
 
 #[test]
 fn test_convert_integer_basic() {
@@ -91,4 +97,287 @@ fn test_convert_fractional_base_conversions() {
 
     let result = convert_fractional_part(&[15], 16, 10, 10);
     assert_eq!(result, vec![9, 3, 7, 5]);
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_convert_integer_beyond_u128() {
+    let mut two_pow_128 = vec![1];
+    two_pow_128.extend(vec![0; 128]);
+    let result = convert_integer_part(&two_pow_128, 2, 10);
+    let expected: Vec<u32> = "340282366920938463463374607431768211456"
+        .chars()
+        .map(|c| c.to_digit(10).unwrap())
+        .collect();
+    assert_eq!(result, expected);
+
+    let result = convert_integer_part(&[15; 64], 16, 10);
+    let expected: Vec<u32> =
+        "115792089237316195423570985008687907853269984665640564039457584007913129639935"
+            .chars()
+            .map(|c| c.to_digit(10).unwrap())
+            .collect();
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_convert_integer_thousands_of_digits() {
+    let decimal: Vec<u32> = (0..5000).map(|i| ((i * 31 + 7) % 10) as u32).collect();
+    let hex = convert_integer_part(&decimal, 10, 16);
+    let back = convert_integer_part(&hex, 16, 10);
+    assert_eq!(back, decimal);
+
+    let mut power = vec![1];
+    power.extend(vec![0; 4096]);
+    let binary = convert_integer_part(&power, 16, 2);
+    assert_eq!(binary.len(), 4096 * 4 + 1);
+    assert_eq!(binary[0], 1);
+    assert!(binary[1..].iter().all(|&d| d == 0));
+}
+
+#[test]
+fn test_convert_fractional_exact_long_input() {
+    // 2^-40 = 0.0000000000009094947017729282379150390625
+    let mut digits = vec![0; 39];
+    digits.push(1);
+    let result = convert_fractional_part(&digits, 2, 10, 100);
+    let expected: Vec<u32> = "0000000000009094947017729282379150390625"
+        .chars()
+        .map(|c| c.to_digit(10).unwrap())
+        .collect();
+    assert_eq!(result, expected);
+
+    let result = convert_fractional_part(&[1; 40], 2, 10, 100);
+    let expected: Vec<u32> = "9999999999990905052982270717620849609375"
+        .chars()
+        .map(|c| c.to_digit(10).unwrap())
+        .collect();
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_convert_fractional_exact_roundtrip() {
+    let digits: Vec<u32> = (0..60).map(|i| (i * 13 + 5) % 16).collect();
+    let decimal = convert_fractional_part(&digits, 16, 10, 1000);
+    assert_eq!(decimal.len(), 238);
+    let back = convert_fractional_part(&decimal, 10, 16, 1000);
+    assert_eq!(back, digits);
+}
+
+#[test]
+fn test_expand_fractional_repeating() {
+    let expansion = expand_fractional_part(&[1], 10, 2, 100);
+    assert_eq!(expansion.prefix, vec![0]);
+    assert_eq!(expansion.repetend, vec![0, 0, 1, 1]);
+    assert!(!expansion.truncated);
+
+    let expansion = expand_fractional_part(&[1], 10, 3, 100);
+    assert_eq!(expansion.prefix, vec![]);
+    assert_eq!(expansion.repetend, vec![0, 0, 2, 2]);
+
+    let expansion = expand_fractional_part(&[1], 3, 10, 100);
+    assert_eq!(expansion.prefix, vec![]);
+    assert_eq!(expansion.repetend, vec![3]);
+}
+
+#[test]
+fn test_expand_fractional_terminating() {
+    let expansion = expand_fractional_part(&[1, 0, 1], 2, 10, 100);
+    assert_eq!(expansion.prefix, vec![6, 2, 5]);
+    assert!(expansion.repetend.is_empty());
+    assert!(!expansion.truncated);
+
+    assert_eq!(
+        expand_fractional_part(&[], 10, 2, 100),
+        FractionalExpansion::default()
+    );
+}
+
+#[test]
+fn test_expand_fractional_truncated() {
+    // 1/7 in base 10 has period 6, so 4 digits cannot close the cycle
+    let seventh = convert_fractional_part(&[1], 7, 10, 20);
+    assert_eq!(seventh[..6], [1, 4, 2, 8, 5, 7]);
+
+    let expansion = expand_fractional_part(&[1], 7, 10, 4);
+    assert_eq!(expansion.prefix, vec![1, 4, 2, 8]);
+    assert!(expansion.repetend.is_empty());
+    assert!(expansion.truncated);
+
+    let expansion = expand_fractional_part(&[1], 7, 10, 6);
+    assert_eq!(expansion.repetend, vec![1, 4, 2, 8, 5, 7]);
+    assert!(!expansion.truncated);
+}
+
+#[test]
+fn test_convert_number_rounding_modes() {
+    let number = Number::new(vec![0], vec![1, 2, 5]);
+    let round = |mode| convert_number(&number, 10, 10, 2, mode).fractional;
+    assert_eq!(round(RoundingMode::TowardZero), vec![1, 2]);
+    assert_eq!(round(RoundingMode::AwayFromZero), vec![1, 3]);
+    assert_eq!(round(RoundingMode::HalfUp), vec![1, 3]);
+    assert_eq!(round(RoundingMode::HalfEven), vec![1, 2]);
+
+    let number = Number::new(vec![0], vec![1, 3, 5]);
+    let round = |mode| convert_number(&number, 10, 10, 2, mode).fractional;
+    assert_eq!(round(RoundingMode::HalfEven), vec![1, 4]);
+
+    let number = Number::new(vec![0], vec![1, 2, 4, 9]);
+    let round = |mode| convert_number(&number, 10, 10, 2, mode).fractional;
+    assert_eq!(round(RoundingMode::HalfUp), vec![1, 2]);
+    assert_eq!(round(RoundingMode::HalfEven), vec![1, 2]);
+    assert_eq!(round(RoundingMode::AwayFromZero), vec![1, 3]);
+}
+
+#[test]
+fn test_convert_number_rounding_carries_into_integer() {
+    let number = Number::new(vec![0], vec![15, 15, 15]);
+    let rounded = convert_number(&number, 16, 16, 2, RoundingMode::HalfUp);
+    assert_eq!(rounded, Number::new(vec![1], vec![0, 0]));
+
+    let number = Number::new(vec![9, 9], vec![9, 6]);
+    let rounded = convert_number(&number, 10, 10, 1, RoundingMode::HalfEven);
+    assert_eq!(rounded, Number::new(vec![1, 0, 0], vec![0]));
+
+    let number = Number::new(vec![2], vec![5]);
+    assert_eq!(
+        convert_number(&number, 10, 10, 0, RoundingMode::HalfEven),
+        Number::new(vec![2], vec![])
+    );
+    assert_eq!(
+        convert_number(&number, 10, 10, 0, RoundingMode::HalfUp),
+        Number::new(vec![3], vec![])
+    );
+}
+
+#[test]
+fn test_convert_number_exact_input_is_not_rounded() {
+    let number = Number::new(vec![1], vec![5]);
+    let converted = convert_number(&number, 10, 2, 10, RoundingMode::AwayFromZero);
+    assert_eq!(converted, Number::new(vec![1], vec![1]));
+}
+
+#[test]
+fn test_convert_scientific() {
+    let number = Number::new(vec![1, 2, 3, 4, 5, 6], vec![]);
+    let scientific = convert_scientific(&number, 10, 16, 3, RoundingMode::TowardZero);
+    assert_eq!(
+        scientific,
+        Scientific {
+            negative: false,
+            digits: vec![1, 14, 2],
+            exponent: 4,
+        }
+    );
+
+    let number = Number::new(vec![0], vec![0, 0, 0, 1, 2, 3]).negated();
+    let scientific = convert_scientific(&number, 10, 10, 10, RoundingMode::TowardZero);
+    assert!(scientific.negative);
+    assert_eq!(scientific.digits, vec![1, 2, 3]);
+    assert_eq!(scientific.exponent, -4);
+}
+
+#[test]
+fn test_convert_scientific_rounding_carries_into_exponent() {
+    let number = Number::new(vec![9, 9, 9], vec![6]);
+    let scientific = convert_scientific(&number, 10, 10, 3, RoundingMode::HalfUp);
+    assert_eq!(scientific.digits, vec![1]);
+    assert_eq!(scientific.exponent, 3);
+
+    let scientific = convert_scientific(&number, 10, 10, 3, RoundingMode::TowardZero);
+    assert_eq!(scientific.digits, vec![9, 9, 9]);
+    assert_eq!(scientific.exponent, 2);
+}
+
+#[test]
+fn test_convert_scientific_zero_and_repetend() {
+    let zero = Number::new(vec![0], vec![0]).negated();
+    let scientific = convert_scientific(&zero, 10, 2, 5, RoundingMode::TowardZero);
+    assert_eq!(
+        scientific,
+        Scientific {
+            negative: false,
+            digits: vec![0],
+            exponent: 0,
+        }
+    );
+
+    let third = Number {
+        repetend: vec![3],
+        ..Number::new(vec![0], vec![])
+    };
+    let scientific = convert_scientific(&third, 10, 3, 4, RoundingMode::TowardZero);
+    assert_eq!(scientific.digits, vec![1]);
+    assert_eq!(scientific.exponent, -1);
+}
+
+#[test]
+fn test_regrouping_matches_arithmetic() {
+    let binary: Vec<u32> = (0..20_000).map(|i| ((i * 7 + i / 3) % 2) as u32).collect();
+    let value = BigUint::from_digits(&binary, 2);
+    for to_base in [4, 8, 16, 32] {
+        assert_eq!(
+            convert_integer_part(&binary, 2, to_base),
+            value.to_digits(to_base)
+        );
+    }
+
+    let octal = value.to_digits(8);
+    assert_eq!(convert_integer_part(&octal, 8, 16), value.to_digits(16));
+
+    let ternary: Vec<u32> = (0..3000).map(|i| ((i * 5 + 1) % 3) as u32).collect();
+    let value = BigUint::from_digits(&ternary, 3);
+    assert_eq!(convert_integer_part(&ternary, 3, 27), value.to_digits(27));
+    let nonary = value.to_digits(9);
+    assert_eq!(convert_integer_part(&nonary, 9, 27), value.to_digits(27));
+    assert_eq!(convert_integer_part(&[0, 0, 1, 2], 9, 3), vec![1, 0, 2]);
+    assert_eq!(convert_integer_part(&[1, 0], 65536, 256), vec![1, 0, 0]);
+    assert_eq!(
+        convert_integer_part(&[3, 4], 1 << 30, 1 << 15),
+        vec![3, 0, 4]
+    );
+}
+
+#[test]
+fn test_regrouping_fractions() {
+    // 0.1 in octal is 0.001 in binary and 0.2 in hexadecimal
+    assert_eq!(convert_fractional_part(&[1], 8, 16, 10), vec![2]);
+    assert_eq!(
+        convert_fractional_part(&[1, 0, 0, 0, 0], 2, 16, 10),
+        vec![8]
+    );
+    assert_eq!(convert_fractional_part(&[1], 27, 9, 10), vec![0, 3]);
+
+    // Truncation keeps the zeros before an unwritten nonzero digit
+    assert_eq!(convert_fractional_part(&[0, 0, 0, 0, 1], 2, 16, 1), vec![0]);
+
+    let digits: Vec<u32> = (0..50_000).map(|i| ((i * 11 + 3) % 16) as u32).collect();
+    let binary = convert_fractional_part(&digits, 16, 2, usize::MAX);
+    assert_eq!(convert_fractional_part(&binary, 2, 16, usize::MAX), digits);
+
+    let expansion = expand_fractional_part(&[1, 0, 1], 2, 8, 1);
+    assert_eq!(expansion.prefix, vec![5]);
+    assert!(!expansion.truncated);
+    let expansion = expand_fractional_part(&[1, 0, 1, 1], 2, 8, 1);
+    assert_eq!(expansion.prefix, vec![5]);
+    assert!(expansion.truncated);
+}
+
+#[test]
+fn test_regrouping_rounds_like_arithmetic() {
+    // 0.11111 in binary is 0.96875 in decimal
+    let binary = Number::new(vec![1, 0], vec![1, 1, 1, 1, 1]);
+    let decimal = Number::new(vec![2], vec![9, 6, 8, 7, 5]);
+    for mode in [
+        RoundingMode::TowardZero,
+        RoundingMode::AwayFromZero,
+        RoundingMode::HalfUp,
+        RoundingMode::HalfEven,
+    ] {
+        for precision in 0..4 {
+            assert_eq!(
+                convert_number(&binary, 2, 16, precision, mode),
+                convert_number(&decimal, 10, 16, precision, mode)
+            );
+        }
+    }
+}
@@ -0,0 +1,117 @@
+use base_converter::converter::RoundingMode;
+use base_converter::fixed::{QFormat, dequantize, quantize};
+use base_converter::formatter::{RepeatNotation, format_expansion, format_number};
+use base_converter::ieee754::exact_decimal;
+use base_converter::parser::parse_number_full;
+
+fn q(spec: &str) -> QFormat {
+    QFormat::parse(spec).unwrap()
+}
+
+#[test]
+fn test_parse_qformat() {
+    assert_eq!(
+        q("Q1.15"),
+        QFormat {
+            signed: true,
+            integer_bits: 1,
+            fraction_bits: 15,
+        }
+    );
+    assert_eq!(q("q15"), q("Q1.15"));
+    assert_eq!(q("Q1.31").total_bits(), 32);
+    assert_eq!(q("UQ8.8").name(), "UQ8.8");
+    assert_eq!(q("UQ16").name(), "UQ0.16");
+
+    assert!(QFormat::parse("Q0.15").is_err());
+    assert!(QFormat::parse("UQ0.0").is_err());
+    assert!(QFormat::parse("Q1.x").is_err());
+    assert!(QFormat::parse("1.15").is_err());
+    assert!(QFormat::parse("Q4294967295.1").is_err());
+    assert!(QFormat::parse("UQ40000.40000").is_err());
+}
+
+#[test]
+fn test_quantize_exact_value() {
+    let number = parse_number_full("-0.375", 10).unwrap();
+    let quantized = quantize(&number, 10, q("Q1.15"), RoundingMode::TowardZero);
+    assert_eq!(format_number(&quantized.integer(10)), "-12288");
+    assert_eq!(format_number(&quantized.integer(16)), "-3000");
+    assert_eq!(quantized.word(16), vec![13, 0, 0, 0]);
+    assert_eq!(exact_decimal(&quantized.value()), "-0.375");
+    assert!(!quantized.saturated);
+
+    let error = quantized.error(&number, 10, 10, 20);
+    assert_eq!(format_expansion(&error, RepeatNotation::Parentheses), "0");
+}
+
+#[test]
+fn test_quantize_rounding_and_error() {
+    let number = parse_number_full("0.1", 10).unwrap();
+    let truncated = quantize(&number, 10, q("Q15"), RoundingMode::TowardZero);
+    assert_eq!(format_number(&truncated.integer(10)), "3276");
+    let nearest = quantize(&number, 10, q("Q15"), RoundingMode::HalfEven);
+    assert_eq!(format_number(&nearest.integer(10)), "3277");
+
+    let error = nearest.error(&number, 10, 10, 30);
+    assert_eq!(
+        format_expansion(&error, RepeatNotation::Parentheses),
+        "0.000006103515625"
+    );
+    let error = truncated.error(&number, 10, 10, 30);
+    assert_eq!(
+        format_expansion(&error, RepeatNotation::Parentheses),
+        "-0.0000244140625"
+    );
+
+    let third = parse_number_full("0.(3)", 10).unwrap();
+    let quantized = quantize(&third, 10, q("Q1.7"), RoundingMode::HalfUp);
+    assert_eq!(format_number(&quantized.integer(10)), "43");
+    let error = quantized.error(&third, 10, 10, 30);
+    assert_eq!(
+        format_expansion(&error, RepeatNotation::Parentheses),
+        "0.0026041(6)"
+    );
+}
+
+#[test]
+fn test_quantize_saturates() {
+    let number = parse_number_full("1.5", 10).unwrap();
+    let quantized = quantize(&number, 10, q("Q1.31"), RoundingMode::TowardZero);
+    assert!(quantized.saturated);
+    assert_eq!(quantized.word(16), vec![7, 15, 15, 15, 15, 15, 15, 15]);
+
+    let number = parse_number_full("-1", 10).unwrap();
+    let quantized = quantize(&number, 10, q("Q1.15"), RoundingMode::TowardZero);
+    assert!(!quantized.saturated);
+    assert_eq!(quantized.word(16), vec![8, 0, 0, 0]);
+
+    let quantized = quantize(&number, 10, q("UQ8.8"), RoundingMode::TowardZero);
+    assert!(quantized.saturated);
+    assert_eq!(format_number(&quantized.integer(10)), "0");
+
+    let number = parse_number_full("300", 10).unwrap();
+    let quantized = quantize(&number, 10, q("UQ8.8"), RoundingMode::TowardZero);
+    assert!(quantized.saturated);
+    assert_eq!(exact_decimal(&quantized.value()), "255.99609375");
+}
+
+#[test]
+fn test_dequantize() {
+    let word = parse_number_full("D000", 16).unwrap();
+    let quantized = dequantize(&word, 16, q("Q1.15")).unwrap();
+    assert_eq!(exact_decimal(&quantized.value()), "-0.375");
+
+    let raw = parse_number_full("-12288", 10).unwrap();
+    let quantized = dequantize(&raw, 10, q("Q1.15")).unwrap();
+    assert_eq!(quantized.word(16), vec![13, 0, 0, 0]);
+
+    let word = parse_number_full("0180", 16).unwrap();
+    let quantized = dequantize(&word, 16, q("UQ8.8")).unwrap();
+    assert_eq!(exact_decimal(&quantized.value()), "1.5");
+
+    assert!(dequantize(&parse_number_full("10000", 16).unwrap(), 16, q("Q1.15")).is_err());
+    assert!(dequantize(&parse_number_full("-1", 10).unwrap(), 10, q("UQ8.8")).is_err());
+    assert!(dequantize(&parse_number_full("-32769", 10).unwrap(), 10, q("Q1.15")).is_err());
+    assert!(dequantize(&parse_number_full("1.5", 10).unwrap(), 10, q("Q1.15")).is_err());
+}
@@ -1,4 +1,11 @@
 use base_converter::formatter::format_result;
+use base_converter::alphabet::Alphabet;
+use base_converter::converter::{Expansion, FractionalExpansion, Scientific};
+use base_converter::formatter::{
+    DigitGrouping, FormatOptions, RepeatNotation, format_expansion, format_number,
+    format_number_with, format_repeating, format_result_with, format_scientific,
+};
+use base_converter::number::Number;
 
 #[test]
 fn test_digit_to_char_numeric() {
@@ -78,4 +85,236 @@ fn test_format_result_hex_examples() {
 fn test_format_result_base36_examples() {
     assert_eq!(format_result(&[7, 4, 11, 11, 14], &[]), "74BBE");
     assert_eq!(format_result(&[22, 4, 7, 11, 3], &[]), "M47B3");
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_format_repeating_parentheses() {
+    let expansion = FractionalExpansion {
+        prefix: vec![0],
+        repetend: vec![0, 0, 1, 1],
+        truncated: false,
+    };
+    assert_eq!(
+        format_repeating(&[0], &expansion, RepeatNotation::Parentheses),
+        "0.0(0011)"
+    );
+
+    let expansion = FractionalExpansion {
+        prefix: vec![],
+        repetend: vec![3],
+        truncated: false,
+    };
+    assert_eq!(
+        format_repeating(&[1], &expansion, RepeatNotation::Parentheses),
+        "1.(3)"
+    );
+}
+
+#[test]
+fn test_format_repeating_overline() {
+    let expansion = FractionalExpansion {
+        prefix: vec![1],
+        repetend: vec![6],
+        truncated: false,
+    };
+    assert_eq!(
+        format_repeating(&[0], &expansion, RepeatNotation::Overline),
+        "0.16\u{0305}"
+    );
+}
+
+#[test]
+fn test_format_repeating_terminating_and_truncated() {
+    let expansion = FractionalExpansion {
+        prefix: vec![8],
+        repetend: vec![],
+        truncated: false,
+    };
+    assert_eq!(
+        format_repeating(&[15, 15], &expansion, RepeatNotation::Parentheses),
+        "FF.8"
+    );
+
+    let expansion = FractionalExpansion {
+        prefix: vec![1, 4, 2, 8],
+        repetend: vec![],
+        truncated: true,
+    };
+    assert_eq!(
+        format_repeating(&[0], &expansion, RepeatNotation::Parentheses),
+        "0.1428..."
+    );
+    assert_eq!(
+        format_repeating(
+            &[],
+            &FractionalExpansion::default(),
+            RepeatNotation::Overline
+        ),
+        "0"
+    );
+}
+
+#[test]
+fn test_format_number_signed() {
+    assert_eq!(format_number(&Number::new(vec![15, 15], vec![8])), "FF.8");
+    assert_eq!(
+        format_number(&Number::new(vec![15, 15], vec![8]).negated()),
+        "-FF.8"
+    );
+    assert_eq!(format_number(&Number::new(vec![], vec![]).negated()), "-0");
+
+    let number = Number {
+        repetend: vec![6],
+        ..Number::new(vec![0], vec![1])
+    };
+    assert_eq!(format_number(&number), "0.1(6)");
+}
+
+#[test]
+fn test_format_expansion_signed() {
+    let expansion = Expansion {
+        negative: true,
+        integer: vec![0],
+        fractional: FractionalExpansion {
+            prefix: vec![],
+            repetend: vec![3],
+            truncated: false,
+        },
+    };
+    assert_eq!(
+        format_expansion(&expansion, RepeatNotation::Parentheses),
+        "-0.(3)"
+    );
+}
+
+#[test]
+fn test_format_number_with_alphabet() {
+    let options = FormatOptions {
+        alphabet: Alphabet::base64(),
+        ..FormatOptions::default()
+    };
+    let number = Number::new(vec![3, 63], vec![32]);
+    assert_eq!(format_number_with(&number, &options), "D/.g");
+    assert_eq!(
+        format_number_with(&Number::new(vec![], vec![]).negated(), &options),
+        "-A"
+    );
+
+    let options = FormatOptions {
+        alphabet: Alphabet::base58(),
+        notation: RepeatNotation::Parentheses,
+        ..FormatOptions::default()
+    };
+    let number = Number {
+        repetend: vec![57],
+        ..Number::new(vec![0], vec![])
+    };
+    assert_eq!(format_number_with(&number, &options), "1.(z)");
+}
+
+#[test]
+fn test_format_lowercase() {
+    let options = FormatOptions {
+        lowercase: true,
+        ..FormatOptions::default()
+    };
+    let number = Number::new(vec![13, 14, 10, 13], vec![11, 14, 14, 15]);
+    assert_eq!(format_number_with(&number, &options), "dead.beef");
+
+    let options = FormatOptions {
+        alphabet: Alphabet::base62(),
+        lowercase: true,
+        ..FormatOptions::default()
+    };
+    let number = Number::new(vec![10, 36], vec![]);
+    assert_eq!(format_number_with(&number, &options), "Aa");
+}
+
+#[test]
+fn test_format_result_grouped() {
+    let options = FormatOptions {
+        grouping: Some(DigitGrouping {
+            size: 4,
+            separator: '_',
+        }),
+        ..FormatOptions::default()
+    };
+    assert_eq!(
+        format_result_with(&[1, 0, 1, 1, 1, 1, 0, 0, 0, 0], &[1, 0, 1, 1, 1], &options),
+        "10_1111_0000.1011_1"
+    );
+    assert_eq!(format_result_with(&[1, 2, 3, 4], &[], &options), "1234");
+    assert_eq!(format_result_with(&[], &[5], &options), "0.5");
+
+    let options = FormatOptions {
+        grouping: Some(DigitGrouping {
+            size: 3,
+            separator: ' ',
+        }),
+        ..FormatOptions::default()
+    };
+    assert_eq!(
+        format_result_with(&[1, 2, 3, 4, 5, 6, 7], &[], &options),
+        "1 234 567"
+    );
+}
+
+#[test]
+fn test_format_grouped_repetend() {
+    let grouping = Some(DigitGrouping {
+        size: 2,
+        separator: '_',
+    });
+    let number = Number {
+        repetend: vec![0, 0, 1, 1],
+        ..Number::new(vec![0], vec![0])
+    };
+    let options = FormatOptions {
+        grouping,
+        ..FormatOptions::default()
+    };
+    assert_eq!(format_number_with(&number, &options), "0.0(0_01_1)");
+
+    let options = FormatOptions {
+        notation: RepeatNotation::Overline,
+        grouping,
+        ..FormatOptions::default()
+    };
+    assert_eq!(
+        format_number_with(&number.negated(), &options),
+        "-0.00\u{0305}_0\u{0305}1\u{0305}_1\u{0305}"
+    );
+}
+
+#[test]
+fn test_format_scientific() {
+    let scientific = Scientific {
+        negative: false,
+        digits: vec![1, 5],
+        exponent: -7,
+    };
+    assert_eq!(
+        format_scientific(&scientific, 10, &FormatOptions::default()),
+        "1.5e-7"
+    );
+
+    let scientific = Scientific {
+        negative: true,
+        digits: vec![1, 14, 2],
+        exponent: 4,
+    };
+    assert_eq!(
+        format_scientific(&scientific, 16, &FormatOptions::default()),
+        "-1.E2@4"
+    );
+
+    let scientific = Scientific {
+        negative: false,
+        digits: vec![0],
+        exponent: 0,
+    };
+    assert_eq!(
+        format_scientific(&scientific, 2, &FormatOptions::default()),
+        "0@0"
+    );
+}
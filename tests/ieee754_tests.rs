@@ -0,0 +1,155 @@
+use base_converter::ieee754::{
+    FloatBits, FloatClass, FloatFormat, encode_float, exact_decimal, special_float,
+};
+use base_converter::parser::parse_number_full;
+
+fn encode_decimal(value: &str, format: FloatFormat) -> u128 {
+    encode_float(&parse_number_full(value, 10).unwrap(), 10, format).to_bits()
+}
+
+#[test]
+fn test_encode_matches_native_floats() {
+    for value in [
+        "3.14159",
+        "0.1",
+        "-2.5",
+        "1e10",
+        "6.02214076e23",
+        "1.17549435e-38",
+    ] {
+        let expected = value.parse::<f32>().unwrap().to_bits() as u128;
+        assert_eq!(
+            encode_decimal(value, FloatFormat::Single),
+            expected,
+            "{}",
+            value
+        );
+
+        let expected = value.parse::<f64>().unwrap().to_bits() as u128;
+        assert_eq!(
+            encode_decimal(value, FloatFormat::Double),
+            expected,
+            "{}",
+            value
+        );
+    }
+}
+
+#[test]
+fn test_encode_subnormals_and_limits() {
+    assert_eq!(encode_decimal("1e-45", FloatFormat::Single), 0x0000_0001);
+    assert_eq!(encode_decimal("5e-324", FloatFormat::Double), 0x1);
+    assert_eq!(encode_decimal("1e-50", FloatFormat::Single), 0);
+    assert_eq!(encode_decimal("-1e-50", FloatFormat::Single), 0x8000_0000);
+    assert_eq!(encode_decimal("65504", FloatFormat::Half), 0x7BFF);
+    assert_eq!(encode_decimal("65520", FloatFormat::Half), 0x7C00);
+    assert_eq!(encode_decimal("1e39", FloatFormat::Single), 0x7F80_0000);
+    assert_eq!(encode_decimal("-1e39", FloatFormat::Single), 0xFF80_0000);
+}
+
+#[test]
+fn test_encode_other_formats() {
+    assert_eq!(encode_decimal("1", FloatFormat::Half), 0x3C00);
+    assert_eq!(encode_decimal("1", FloatFormat::BFloat16), 0x3F80);
+    assert_eq!(encode_decimal("3.140625", FloatFormat::BFloat16), 0x4049);
+    assert_eq!(
+        encode_decimal("1.5", FloatFormat::Quad),
+        0x3FFF_8000_0000_0000_0000_0000_0000_0000
+    );
+
+    let hex = parse_number_full("1.8p3", 16).unwrap();
+    assert_eq!(
+        encode_float(&hex, 16, FloatFormat::Single).to_bits(),
+        12f32.to_bits() as u128
+    );
+}
+
+#[test]
+fn test_decode_exact_values() {
+    let bits = FloatBits::from_bits(FloatFormat::Double, 0.1f64.to_bits() as u128).unwrap();
+    assert_eq!(bits.classify(), FloatClass::Normal);
+    assert_eq!(
+        exact_decimal(&bits.value().unwrap()),
+        "0.1000000000000000055511151231257827021181583404541015625"
+    );
+
+    let bits = FloatBits::from_bits(FloatFormat::Single, 0xC020_0000).unwrap();
+    assert!(bits.sign);
+    assert_eq!(bits.exponent, 128);
+    assert_eq!(bits.unbiased_exponent(), 1);
+    assert_eq!(exact_decimal(&bits.value().unwrap()), "-2.5");
+
+    let bits = FloatBits::from_bits(FloatFormat::Half, 0x0001).unwrap();
+    assert_eq!(bits.classify(), FloatClass::Subnormal);
+    assert_eq!(
+        exact_decimal(&bits.value().unwrap()),
+        "0.000000059604644775390625"
+    );
+
+    let bits = FloatBits::from_bits(FloatFormat::Double, 1 << 63).unwrap();
+    assert_eq!(bits.classify(), FloatClass::Zero);
+    assert_eq!(exact_decimal(&bits.value().unwrap()), "-0");
+}
+
+#[test]
+fn test_decode_specials() {
+    let bits = FloatBits::from_bits(FloatFormat::Single, 0xFF80_0000).unwrap();
+    assert_eq!(bits.classify(), FloatClass::Infinite);
+    assert!(bits.value().is_none());
+    assert_eq!(bits.nan_payload(), None);
+
+    let bits = FloatBits::from_bits(FloatFormat::Single, 0x7FC0_0123).unwrap();
+    assert_eq!(bits.classify(), FloatClass::QuietNan);
+    assert_eq!(bits.nan_payload(), Some(0x123));
+
+    let bits = FloatBits::from_bits(FloatFormat::Single, 0x7F80_0001).unwrap();
+    assert_eq!(bits.classify(), FloatClass::SignalingNan);
+    assert_eq!(bits.nan_payload(), Some(1));
+
+    assert!(FloatBits::from_bits(FloatFormat::Half, 0x1_0000).is_err());
+}
+
+#[test]
+fn test_bits_roundtrip() {
+    for pattern in [0u128, 0x3C00, 0x7BFF, 0x8001, 0x7C00, 0x7E00, 0xFD01] {
+        let bits = FloatBits::from_bits(FloatFormat::Half, pattern).unwrap();
+        assert_eq!(bits.to_bits(), pattern);
+    }
+}
+
+#[test]
+fn test_special_float() {
+    let format = FloatFormat::Single;
+    assert_eq!(
+        special_float("-Infinity", format)
+            .unwrap()
+            .unwrap()
+            .to_bits(),
+        0xFF80_0000
+    );
+    assert_eq!(
+        special_float("nan", format).unwrap().unwrap().to_bits(),
+        0x7FC0_0000
+    );
+    assert_eq!(
+        special_float("NaN(0x2A)", format)
+            .unwrap()
+            .unwrap()
+            .to_bits(),
+        0x7FC0_002A
+    );
+    assert_eq!(
+        special_float("-snan(7)", format)
+            .unwrap()
+            .unwrap()
+            .to_bits(),
+        0xFF80_0007
+    );
+    assert_eq!(
+        special_float("snan", format).unwrap().unwrap().classify(),
+        FloatClass::SignalingNan
+    );
+    assert!(special_float("1.5", format).unwrap().is_none());
+    assert!(special_float("snan(0)", format).is_err());
+    assert!(special_float("nan(0x400000)", format).is_err());
+}
@@ -1,4 +1,9 @@
 use base_converter::{convert_fractional_part, convert_integer_part, format_result, parse_number};
+use base_converter::{
+    Alphabet, FormatOptions, RepeatNotation, RoundingMode, convert_number, convert_scientific,
+    expand_number, format_expansion, format_number, format_number_with, format_scientific,
+    parse_literal, parse_number_full, parse_number_with,
+};
 
 #[test]
 fn test_end_to_end_binary_to_decimal() {
@@ -112,4 +117,145 @@ fn test_error_propagation() {
     assert!(parse_number("123", 37).is_err());
     assert!(parse_number("G", 16).is_err());
     assert!(parse_number("1.2.3", 10).is_err());
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_repeating_input_converts_exactly() {
+    let number = parse_number_full("0.(3)", 10).unwrap();
+    let converted = convert_number(&number, 10, 3, 10, RoundingMode::TowardZero);
+    assert_eq!(
+        format_result(&converted.integer, &converted.fractional),
+        "0.1"
+    );
+
+    let number = parse_number_full("0.1(6)", 10).unwrap();
+    let converted = convert_number(&number, 10, 6, 10, RoundingMode::TowardZero);
+    assert_eq!(
+        format_result(&converted.integer, &converted.fractional),
+        "0.1"
+    );
+
+    let number = parse_number_full("0.0(0011)", 2).unwrap();
+    let converted = convert_number(&number, 2, 10, 10, RoundingMode::TowardZero);
+    assert_eq!(
+        format_result(&converted.integer, &converted.fractional),
+        "0.1"
+    );
+}
+
+#[test]
+fn test_repeating_input_carries_into_integer() {
+    let number = parse_number_full("0.(9)", 10).unwrap();
+    let converted = convert_number(&number, 10, 2, 10, RoundingMode::TowardZero);
+    assert_eq!(
+        format_result(&converted.integer, &converted.fractional),
+        "1"
+    );
+
+    let number = parse_number_full("1F.(F)", 16).unwrap();
+    let converted = convert_number(&number, 16, 10, 10, RoundingMode::TowardZero);
+    assert_eq!(
+        format_result(&converted.integer, &converted.fractional),
+        "32"
+    );
+}
+
+#[test]
+fn test_repeating_roundtrip() {
+    let number = parse_number_full("12.3(45)", 10).unwrap();
+    let expansion = expand_number(&number, 10, 7, 100);
+    let in_base_7 = format_expansion(&expansion, RepeatNotation::Parentheses);
+
+    let back = parse_number_full(&in_base_7, 7).unwrap();
+    let expansion = expand_number(&back, 7, 10, 100);
+    assert_eq!(
+        format_expansion(&expansion, RepeatNotation::Parentheses),
+        "12.3(45)"
+    );
+}
+
+#[test]
+fn test_negative_end_to_end() {
+    let number = parse_number_full("-FF.8", 16).unwrap();
+    let converted = convert_number(&number, 16, 2, 10, RoundingMode::TowardZero);
+    assert_eq!(format_number(&converted), "-11111111.1");
+
+    let decimal = convert_number(&number, 16, 10, 10, RoundingMode::TowardZero);
+    assert_eq!(format_number(&decimal), "-255.5");
+
+    let number = parse_number_full("-0.1", 10).unwrap();
+    let expansion = expand_number(&number, 10, 2, 100);
+    assert_eq!(
+        format_expansion(&expansion, RepeatNotation::Parentheses),
+        "-0.0(0011)"
+    );
+}
+
+#[test]
+fn test_negative_rounding_is_symmetric() {
+    let number = parse_number_full("-0.125", 10).unwrap();
+    let rounded = convert_number(&number, 10, 10, 2, RoundingMode::HalfUp);
+    assert_eq!(format_number(&rounded), "-0.13");
+    let rounded = convert_number(&number, 10, 10, 2, RoundingMode::TowardZero);
+    assert_eq!(format_number(&rounded), "-0.12");
+}
+
+#[test]
+fn test_negative_zero_drops_sign() {
+    let number = parse_number_full("-0.0", 10).unwrap();
+    let converted = convert_number(&number, 10, 2, 10, RoundingMode::TowardZero);
+    assert_eq!(format_number(&converted), "0");
+}
+
+#[test]
+fn test_base58_to_hex() {
+    let number = parse_number_with("5Q", 58, &Alphabet::base58()).unwrap();
+    let converted = convert_number(&number, 58, 16, 10, RoundingMode::TowardZero);
+    assert_eq!(format_number(&converted), "FF");
+}
+
+#[test]
+fn test_base_256_roundtrip() {
+    let symbols: String = (0..256u32)
+        .map(|i| char::from_u32(0x100 + i).unwrap())
+        .collect();
+    let options = FormatOptions {
+        alphabet: Alphabet::new(&symbols).unwrap(),
+        ..FormatOptions::default()
+    };
+
+    let number = parse_number_full("DEADBEEF", 16).unwrap();
+    let converted = convert_number(&number, 16, 256, 10, RoundingMode::TowardZero);
+    assert_eq!(converted.integer, vec![0xDE, 0xAD, 0xBE, 0xEF]);
+
+    let text = format_number_with(&converted, &options);
+    let back = parse_number_with(&text, 256, &options.alphabet).unwrap();
+    let hex = convert_number(&back, 256, 16, 10, RoundingMode::TowardZero);
+    assert_eq!(format_number(&hex), "DEADBEEF");
+}
+
+#[test]
+fn test_radix_prefix_end_to_end() {
+    let (base, number) = parse_literal("0xFF.8", None, &Alphabet::standard()).unwrap();
+    let converted = convert_number(&number, base, 2, 10, RoundingMode::TowardZero);
+    assert_eq!(format_number(&converted), "11111111.1");
+
+    let (base, number) = parse_literal("-8#17#", None, &Alphabet::standard()).unwrap();
+    let converted = convert_number(&number, base, 10, 10, RoundingMode::TowardZero);
+    assert_eq!(format_number(&converted), "-15");
+}
+
+#[test]
+fn test_scientific_roundtrip() {
+    let number = parse_number_full("-1.5e-7", 10).unwrap();
+    let scientific = convert_scientific(&number, 10, 16, 6, RoundingMode::HalfEven);
+    let hex = format_scientific(&scientific, 16, &FormatOptions::default());
+    assert_eq!(hex, "-2.843EC@-6");
+
+    let back = parse_number_full(&hex, 16).unwrap();
+    let scientific = convert_scientific(&back, 16, 10, 4, RoundingMode::HalfEven);
+    assert_eq!(
+        format_scientific(&scientific, 10, &FormatOptions::default()),
+        "-1.5e-7"
+    );
+}
@@ -0,0 +1,95 @@
+use base_converter::bigint::BigUint;
+use base_converter::formatter::format_number;
+use base_converter::mixed::{
+    MixedDigits, MixedRadix, convert_from_mixed_radix, convert_to_mixed_radix, format_mixed_radix,
+    parse_mixed_radix,
+};
+use base_converter::parser::parse_number_full;
+
+fn to_mixed(value: &str, spec: &str) -> String {
+    let radix = MixedRadix::parse(spec).unwrap();
+    let number = parse_number_full(value, 10).unwrap();
+    format_mixed_radix(&convert_to_mixed_radix(&number, 10, &radix).unwrap())
+}
+
+fn to_decimal(value: &str, spec: &str) -> String {
+    let radix = MixedRadix::parse(spec).unwrap();
+    let digits = parse_mixed_radix(value, &radix).unwrap();
+    format_number(&convert_from_mixed_radix(&digits, &radix, 10))
+}
+
+#[test]
+fn test_parse_specs() {
+    assert_eq!(
+        MixedRadix::parse("24:60:60").unwrap(),
+        MixedRadix::Fixed(vec![24, 60, 60])
+    );
+    assert_eq!(
+        MixedRadix::parse("_:24:60:60").unwrap(),
+        MixedRadix::parse("time").unwrap()
+    );
+    assert_eq!(
+        MixedRadix::parse("Factoradic").unwrap(),
+        MixedRadix::Factorial
+    );
+    assert_eq!(MixedRadix::parse("3:12").unwrap().name(), "_:3:12");
+    assert!(MixedRadix::parse("24:1").is_err());
+    assert!(MixedRadix::parse("24::60").is_err());
+    assert!(MixedRadix::parse("").is_err());
+}
+
+#[test]
+fn test_time_conversions() {
+    assert_eq!(to_mixed("90061", "24:60:60"), "1:1:1:1");
+    assert_eq!(to_mixed("3661", "24:60:60"), "1:1:1");
+    assert_eq!(to_mixed("86400", "time"), "1:0:0:0");
+    assert_eq!(to_mixed("59", "time"), "59");
+    assert_eq!(to_mixed("-61", "time"), "-1:1");
+    assert_eq!(to_mixed("0", "time"), "0");
+    assert_eq!(to_decimal("1:01:01:01", "time"), "90061");
+    assert_eq!(to_decimal("400:0:0:0", "time"), "34560000");
+    assert_eq!(to_decimal("-1:1", "time"), "-61");
+}
+
+#[test]
+fn test_old_units() {
+    // Pounds, shillings and pence: 20 shillings to the pound, 12 pence to the shilling
+    assert_eq!(to_mixed("1000", "20:12"), "4:3:4");
+    assert_eq!(to_decimal("4:3:4", "20:12"), "1000");
+}
+
+#[test]
+fn test_factoradic() {
+    assert_eq!(to_mixed("463", "factoradic"), "3:4:1:0:1:0");
+    assert_eq!(to_mixed("1", "factoradic"), "1:0");
+    assert_eq!(to_mixed("5", "factoradic"), "2:1:0");
+    assert_eq!(to_mixed("6", "factoradic"), "1:0:0:0");
+    assert_eq!(to_mixed("0", "factoradic"), "0");
+    assert_eq!(to_decimal("3:4:1:0:1:0", "factoradic"), "463");
+    assert_eq!(to_decimal("2:1:0", "factoradic"), "5");
+
+    let radix = MixedRadix::Factorial;
+    for value in 0..=5040u64 {
+        let digits = MixedDigits::from_integer(false, &BigUint::from_u64(value), &radix);
+        assert_eq!(digits.magnitude(&radix), BigUint::from_u64(value));
+    }
+}
+
+#[test]
+fn test_large_leading_digit() {
+    let value = "123456789012345678901234567890";
+    let text = to_mixed(value, "24:60:60");
+    assert_eq!(to_decimal(&text, "24:60:60"), value);
+}
+
+#[test]
+fn test_mixed_radix_errors() {
+    let time = MixedRadix::parse("time").unwrap();
+    assert!(parse_mixed_radix("1:60", &time).is_err());
+    assert!(parse_mixed_radix("1:2:3:4:5", &time).is_err());
+    assert!(parse_mixed_radix("1::2", &time).is_err());
+    assert!(parse_mixed_radix("1:x", &time).is_err());
+    assert!(parse_mixed_radix("1:0", &MixedRadix::Factorial).is_ok());
+    assert!(parse_mixed_radix("1:1", &MixedRadix::Factorial).is_err());
+    assert!(convert_to_mixed_radix(&parse_number_full("1.5", 10).unwrap(), 10, &time).is_err());
+}
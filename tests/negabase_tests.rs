@@ -0,0 +1,93 @@
+use base_converter::converter::RoundingMode;
+use base_converter::formatter::{RepeatNotation, format_expansion, format_number};
+use base_converter::negabase::{convert_signed_radix, expand_signed_radix};
+use base_converter::number::Number;
+use base_converter::parser::parse_number_full;
+
+fn convert(value: &str, from: i32, to: i32) -> String {
+    let number = parse_number_full(value, from.unsigned_abs()).unwrap();
+    format_number(&convert_signed_radix(
+        &number,
+        from,
+        to,
+        10,
+        RoundingMode::TowardZero,
+    ))
+}
+
+#[test]
+fn test_integers_to_negabinary() {
+    assert_eq!(convert("0", 10, -2), "0");
+    assert_eq!(convert("1", 10, -2), "1");
+    assert_eq!(convert("2", 10, -2), "110");
+    assert_eq!(convert("6", 10, -2), "11010");
+    assert_eq!(convert("-1", 10, -2), "11");
+    assert_eq!(convert("-6", 10, -2), "1110");
+    assert_eq!(convert("-0", 10, -2), "0");
+}
+
+#[test]
+fn test_negabase_to_positive() {
+    assert_eq!(convert("11010", -2, 10), "6");
+    assert_eq!(convert("1110", -2, 10), "-6");
+    assert_eq!(convert("12.3", -10, 10), "-8.3");
+    assert_eq!(convert("19", -10, 10), "-1");
+    assert_eq!(convert("11F", -16, 16), "FF");
+    assert_eq!(convert("-11010", -2, 10), "-6");
+}
+
+#[test]
+fn test_fractions_to_negabase() {
+    assert_eq!(convert("0.5", 10, -2), "1.1");
+    assert_eq!(convert("-0.75", 10, -10), "0.85");
+    assert_eq!(convert("0.25", 10, -2), "0.01");
+    assert_eq!(convert("-8.3", 10, -10), "12.3");
+}
+
+#[test]
+fn test_negabase_to_negabase() {
+    assert_eq!(convert("11", -3, -2), "10");
+    assert_eq!(convert("1.1", -2, -10), "1.5");
+}
+
+#[test]
+fn test_negabase_roundtrip() {
+    for value in -64i32..=64 {
+        let text = value.to_string();
+        let negabinary = convert(&text, 10, -2);
+        assert!(!negabinary.starts_with('-'));
+        assert_eq!(convert(&negabinary, -2, 10), text);
+
+        let negaternary = convert(&text, 10, -3);
+        assert_eq!(convert(&negaternary, -3, 10), text);
+    }
+}
+
+#[test]
+fn test_negabase_precision_and_rounding() {
+    let number = parse_number_full("0.1", 10).unwrap();
+    let truncated = convert_signed_radix(&number, 10, -2, 4, RoundingMode::TowardZero);
+    assert_eq!(format_number(&truncated), "0.0001");
+    let rounded = convert_signed_radix(&number, 10, -2, 4, RoundingMode::HalfUp);
+    assert_eq!(format_number(&rounded), "0.011");
+}
+
+#[test]
+fn test_negabase_repetend() {
+    let number = Number {
+        repetend: vec![1],
+        ..Number::new(vec![1], vec![])
+    };
+    let expansion = expand_signed_radix(&number, -2, 10, 20);
+    assert_eq!(
+        format_expansion(&expansion, RepeatNotation::Parentheses),
+        "0.(6)"
+    );
+
+    let number = parse_number_full("0.(01)", 2).unwrap();
+    let expansion = expand_signed_radix(&number, -2, 3, 20);
+    assert_eq!(
+        format_expansion(&expansion, RepeatNotation::Parentheses),
+        "0.1"
+    );
+}
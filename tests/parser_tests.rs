@@ -1,4 +1,8 @@
 use base_converter::parser::parse_number;
+use base_converter::alphabet::Alphabet;
+use base_converter::parser::{
+    detect_radix, parse_literal, parse_number_full, parse_number_with, parse_signed_radix,
+};
 
 #[test]
 fn test_char_to_digit_valid() {
@@ -120,4 +124,382 @@ fn test_parse_number_edge_bases() {
 
     let (int_part, _) = parse_number("ZZ", 36).unwrap();
     assert_eq!(int_part, vec![35, 35]);
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_parse_number_repetend() {
+    let number = parse_number_full("0.(3)", 10).unwrap();
+    assert_eq!(number.integer, vec![0]);
+    assert_eq!(number.fractional, vec![]);
+    assert_eq!(number.repetend, vec![3]);
+
+    let number = parse_number_full("0.1(6)", 10).unwrap();
+    assert_eq!(number.fractional, vec![1]);
+    assert_eq!(number.repetend, vec![6]);
+
+    let number = parse_number_full(".0(0011)", 2).unwrap();
+    assert_eq!(number.integer, vec![0]);
+    assert_eq!(number.fractional, vec![0]);
+    assert_eq!(number.repetend, vec![0, 0, 1, 1]);
+
+    assert!(parse_number_full("1.5", 10).unwrap().repetend.is_empty());
+}
+
+#[test]
+fn test_parse_number_invalid_repetend() {
+    assert!(parse_number_full("0.()", 10).is_err());
+    assert!(parse_number_full("0.(3", 10).is_err());
+    assert!(parse_number_full("0.3)", 10).is_err());
+    assert!(parse_number_full("0.(3)1", 10).is_err());
+    assert!(parse_number_full("0.(3)(3)", 10).is_err());
+    assert!(parse_number_full("(3).0", 10).is_err());
+    assert!(parse_number_full("0.(2)", 2).is_err());
+}
+
+#[test]
+fn test_parse_number_sign() {
+    let number = parse_number_full("-FF.8", 16).unwrap();
+    assert!(number.negative);
+    assert_eq!(number.integer, vec![15, 15]);
+    assert_eq!(number.fractional, vec![8]);
+
+    let number = parse_number_full("+101", 2).unwrap();
+    assert!(!number.negative);
+    assert_eq!(number.integer, vec![1, 0, 1]);
+
+    let number = parse_number_full("-.5", 10).unwrap();
+    assert!(number.negative);
+    assert_eq!(number.integer, vec![0]);
+
+    assert!(!parse_number_full("12", 10).unwrap().negative);
+}
+
+#[test]
+fn test_parse_number_invalid_sign() {
+    assert!(parse_number_full("-", 10).is_err());
+    assert!(parse_number_full("--1", 10).is_err());
+    assert!(parse_number_full("+-1", 10).is_err());
+    assert!(parse_number_full("1-", 10).is_err());
+    assert!(parse_number_full("1.-5", 10).is_err());
+}
+
+#[test]
+fn test_parse_number_with_alphabet() {
+    let number = parse_number_with("2g", 58, &Alphabet::base58()).unwrap();
+    assert_eq!(number.integer, vec![1, 39]);
+
+    let number = parse_number_with("aA", 62, &Alphabet::base62()).unwrap();
+    assert_eq!(number.integer, vec![36, 10]);
+
+    let number = parse_number_with("+/.g", 64, &Alphabet::base64()).unwrap();
+    assert!(!number.negative);
+    assert_eq!(number.integer, vec![62, 63]);
+    assert_eq!(number.fractional, vec![32]);
+
+    let number = parse_number_with("-yx", 3, &Alphabet::new("xyz").unwrap()).unwrap();
+    assert!(number.negative);
+    assert_eq!(number.integer, vec![1, 0]);
+}
+
+#[test]
+fn test_parse_number_with_alphabet_errors() {
+    assert!(parse_number_with("0", 58, &Alphabet::base58()).is_err());
+    assert!(parse_number_with("z", 40, &Alphabet::base58()).is_err());
+    assert!(parse_number_with("1", 59, &Alphabet::base58()).is_err());
+    assert!(parse_number_full("1", 37).is_err());
+}
+
+#[test]
+fn test_parse_number_case_sensitive() {
+    let strict = Alphabet::standard().case_sensitive();
+    let number = parse_number_with("ABC.DEF", 16, &strict).unwrap();
+    assert_eq!(number.integer, vec![10, 11, 12]);
+
+    let error = parse_number_with("aBc", 16, &strict).unwrap_err();
+    assert!(error.contains("Ambiguous case"));
+
+    let number = parse_number_with("zZ", 62, &Alphabet::base62()).unwrap();
+    assert_eq!(number.integer, vec![61, 35]);
+}
+
+#[test]
+fn test_detect_radix_prefixes() {
+    let standard = Alphabet::standard();
+    assert_eq!(
+        detect_radix("0xFF", None, &standard).unwrap(),
+        (16, "FF".to_string())
+    );
+    assert_eq!(
+        detect_radix("0XfF", None, &standard).unwrap(),
+        (16, "fF".to_string())
+    );
+    assert_eq!(
+        detect_radix("0b1010", None, &standard).unwrap(),
+        (2, "1010".to_string())
+    );
+    assert_eq!(
+        detect_radix("0o17", None, &standard).unwrap(),
+        (8, "17".to_string())
+    );
+    assert_eq!(
+        detect_radix("#xFF", None, &standard).unwrap(),
+        (16, "FF".to_string())
+    );
+    assert_eq!(
+        detect_radix("#b101", None, &standard).unwrap(),
+        (2, "101".to_string())
+    );
+    assert_eq!(
+        detect_radix("-0x1.8", None, &standard).unwrap(),
+        (16, "-1.8".to_string())
+    );
+}
+
+#[test]
+fn test_detect_radix_suffixes_and_ada() {
+    let standard = Alphabet::standard();
+    assert_eq!(
+        detect_radix("FFh", None, &standard).unwrap(),
+        (16, "FF".to_string())
+    );
+    assert_eq!(
+        detect_radix("0FFH", None, &standard).unwrap(),
+        (16, "0FF".to_string())
+    );
+    assert_eq!(
+        detect_radix("1010b", None, &standard).unwrap(),
+        (2, "1010".to_string())
+    );
+    assert_eq!(
+        detect_radix("16#FF#", None, &standard).unwrap(),
+        (16, "FF".to_string())
+    );
+    assert_eq!(
+        detect_radix("2#1.1#", None, &standard).unwrap(),
+        (2, "1.1".to_string())
+    );
+    assert_eq!(
+        detect_radix("+36#ZZ#", None, &standard).unwrap(),
+        (36, "+ZZ".to_string())
+    );
+}
+
+#[test]
+fn test_detect_radix_with_given_base() {
+    let standard = Alphabet::standard();
+    assert_eq!(
+        detect_radix("0xFF", Some(16), &standard).unwrap(),
+        (16, "FF".to_string())
+    );
+    assert_eq!(
+        detect_radix("123", Some(10), &standard).unwrap(),
+        (10, "123".to_string())
+    );
+
+    // marker characters that are digits of the given base stay digits
+    assert_eq!(
+        detect_radix("0b1", Some(16), &standard).unwrap(),
+        (16, "0b1".to_string())
+    );
+    assert_eq!(
+        detect_radix("1010b", Some(16), &standard).unwrap(),
+        (16, "1010b".to_string())
+    );
+}
+
+#[test]
+fn test_detect_radix_errors() {
+    let standard = Alphabet::standard();
+    assert!(detect_radix("0xFF", Some(10), &standard).is_err());
+    assert!(detect_radix("FFh", Some(10), &standard).is_err());
+    assert!(detect_radix("16#FF#", Some(8), &standard).is_err());
+    assert!(detect_radix("123", None, &standard).is_err());
+    assert!(detect_radix("FFb", None, &standard).is_err());
+    assert!(detect_radix("0x-FF", None, &standard).is_err());
+    assert!(detect_radix("16#+FF#", None, &standard).is_err());
+    assert_eq!(
+        detect_radix("-0xFF", None, &standard),
+        Ok((16, "-FF".to_string()))
+    );
+}
+
+#[test]
+fn test_parse_literal() {
+    let standard = Alphabet::standard();
+    let (base, number) = parse_literal("0x1F.8", None, &standard).unwrap();
+    assert_eq!(base, 16);
+    assert_eq!(number.integer, vec![1, 15]);
+    assert_eq!(number.fractional, vec![8]);
+
+    let (base, number) = parse_literal("-1010b", None, &standard).unwrap();
+    assert_eq!(base, 2);
+    assert!(number.negative);
+    assert_eq!(number.integer, vec![1, 0, 1, 0]);
+
+    assert!(parse_literal("0x", None, &standard).is_err());
+    assert!(parse_literal("0xG", None, &standard).is_err());
+}
+
+#[test]
+fn test_parse_number_digit_separators() {
+    let number = parse_number_full("1111_0000", 2).unwrap();
+    assert_eq!(number.integer, vec![1, 1, 1, 1, 0, 0, 0, 0]);
+
+    let number = parse_number_full("1 2", 10).unwrap();
+    assert_eq!(number.integer, vec![1, 2]);
+
+    let number = parse_number_full("-1'000.000_1", 10).unwrap();
+    assert!(number.negative);
+    assert_eq!(number.integer, vec![1, 0, 0, 0]);
+    assert_eq!(number.fractional, vec![0, 0, 0, 1]);
+
+    let number = parse_number_full("0.1(2_3)", 10).unwrap();
+    assert_eq!(number.repetend, vec![2, 3]);
+}
+
+#[test]
+fn test_parse_number_misplaced_separators() {
+    assert!(parse_number_full("_1", 10).is_err());
+    assert!(parse_number_full("1_", 10).is_err());
+    assert!(parse_number_full("1__0", 10).is_err());
+    assert!(parse_number_full("1_.0", 10).is_err());
+    assert!(parse_number_full("1._0", 10).is_err());
+    assert!(parse_number_full("-_1", 10).is_err());
+}
+
+#[test]
+fn test_separator_used_as_digit_is_kept() {
+    let alphabet = Alphabet::new("01_").unwrap();
+    let number = parse_number_with("1_0", 3, &alphabet).unwrap();
+    assert_eq!(number.integer, vec![1, 2, 0]);
+}
+
+#[test]
+fn test_detect_radix_with_separators() {
+    let standard = Alphabet::standard();
+    assert_eq!(
+        detect_radix("0xFF'FF", None, &standard).unwrap(),
+        (16, "FFFF".to_string())
+    );
+    assert_eq!(
+        detect_radix("1111_0000b", None, &standard).unwrap(),
+        (2, "11110000".to_string())
+    );
+}
+
+#[test]
+fn test_parse_number_decimal_exponent() {
+    let number = parse_number_full("1.5e-7", 10).unwrap();
+    assert_eq!(number.integer, vec![0]);
+    assert_eq!(number.fractional, vec![0, 0, 0, 0, 0, 0, 1, 5]);
+
+    let number = parse_number_full("-2.5E+3", 10).unwrap();
+    assert!(number.negative);
+    assert_eq!(number.integer, vec![2, 5, 0, 0]);
+    assert!(number.fractional.is_empty());
+
+    let number = parse_number_full("0.1(6)e2", 10).unwrap();
+    assert_eq!(number.integer, vec![1, 6]);
+    assert_eq!(number.repetend, vec![6]);
+}
+
+#[test]
+fn test_parse_number_binary_exponent() {
+    let number = parse_number_full("1.8p3", 16).unwrap();
+    assert_eq!(number.integer, vec![12]);
+    assert_eq!(number.fractional, vec![0]);
+
+    let number = parse_number_full("1p-2", 16).unwrap();
+    assert_eq!(number.integer, vec![0]);
+    assert_eq!(number.fractional, vec![4]);
+
+    let number = parse_number_full("1.1p1", 2).unwrap();
+    assert_eq!(number.integer, vec![1, 1]);
+
+    let (base, number) = parse_literal("0x1.8p8", None, &Alphabet::standard()).unwrap();
+    assert_eq!(base, 16);
+    assert_eq!(number.integer, vec![1, 8, 0]);
+}
+
+#[test]
+fn test_parse_number_base_exponent() {
+    let number = parse_number_full("FF@2", 16).unwrap();
+    assert_eq!(number.integer, vec![15, 15, 0, 0]);
+
+    let number = parse_number_full("12@-3", 3).unwrap();
+    assert_eq!(number.integer, vec![0]);
+    assert_eq!(number.fractional, vec![0, 1, 2]);
+
+    let number = parse_number_full("0.(12)@3", 3).unwrap();
+    assert_eq!(number.integer, vec![1, 2, 1]);
+    assert_eq!(number.repetend, vec![2, 1]);
+}
+
+#[test]
+fn test_exponent_marker_as_digit() {
+    let number = parse_number_full("1e5", 16).unwrap();
+    assert_eq!(number.integer, vec![1, 14, 5]);
+    let number = parse_number_full("1p", 36).unwrap();
+    assert_eq!(number.integer, vec![1, 25]);
+}
+
+#[test]
+fn test_parse_number_exponent_errors() {
+    assert!(parse_number_full("1e5", 8).is_err());
+    assert!(parse_number_full("1p3", 10).is_err());
+    assert!(parse_number_full("1.5e", 10).is_err());
+    assert!(parse_number_full("1.5e1.5", 10).is_err());
+    assert!(parse_number_full("e5", 10).is_err());
+    assert!(parse_number_full("1e99999999", 10).is_err());
+    assert!(parse_number_full("0.(1)p1", 16).is_err());
+}
+
+#[test]
+fn test_parse_signed_radix() {
+    let standard = Alphabet::standard();
+    let number = parse_signed_radix("11010", -2, &standard).unwrap();
+    assert_eq!(number.integer, vec![1, 1, 0, 1, 0]);
+    let number = parse_signed_radix("1@2", -10, &standard).unwrap();
+    assert_eq!(number.integer, vec![1, 0, 0]);
+    let number = parse_signed_radix("FF", 16, &standard).unwrap();
+    assert_eq!(number.integer, vec![15, 15]);
+
+    assert!(parse_signed_radix("2", -2, &standard).is_err());
+    assert!(parse_signed_radix("1", -1, &standard).is_err());
+    assert!(parse_signed_radix("1", -37, &standard).is_err());
+    assert!(parse_signed_radix("1e2", -10, &standard).is_err());
+    assert!(parse_signed_radix("1p2", -16, &standard).is_err());
+}
+
+#[test]
+fn test_parse_number_rejects_extended_syntax() {
+    for value in ["-5", "+5", "1_000", "0.(3)", "1e3"] {
+        assert!(parse_number(value, 10).is_err(), "{}", value);
+        assert!(parse_number_full(value, 10).is_ok(), "{}", value);
+    }
+}
+
+#[test]
+fn test_parse_number_rejects_characters_before_parsing() {
+    assert_eq!(
+        parse_number("1(", 10),
+        Err("Invalid character: '('".to_string())
+    );
+    assert_eq!(
+        parse_number("'1", 10),
+        Err("Invalid character: '''".to_string())
+    );
+    assert_eq!(
+        parse_number("1e5", 10),
+        Err("Digit 'e' is invalid for base 10".to_string())
+    );
+    assert_eq!(
+        parse_number("1e1000000", 10),
+        Err("Digit 'e' is invalid for base 10".to_string())
+    );
+    assert!(
+        parse_number("1(", 40)
+            .unwrap_err()
+            .starts_with("Base must be")
+    );
+}
@@ -0,0 +1,59 @@
+use base_converter::bigint::BigUint;
+use base_converter::formatter::format_number;
+use base_converter::number::Number;
+use base_converter::parser::parse_number_full;
+use base_converter::phinary::{from_phinary, to_phinary};
+
+fn phinary(value: u64) -> String {
+    format_number(&to_phinary(&BigUint::from_u64(value)))
+}
+
+fn integer(text: &str) -> Result<BigUint, String> {
+    from_phinary(&parse_number_full(text, 2).unwrap())
+}
+
+#[test]
+fn test_small_integers() {
+    assert_eq!(phinary(0), "0");
+    assert_eq!(phinary(1), "1");
+    assert_eq!(phinary(2), "10.01");
+    assert_eq!(phinary(3), "100.01");
+    assert_eq!(phinary(4), "101.01");
+    assert_eq!(phinary(5), "1000.1001");
+    assert_eq!(phinary(6), "1010.0001");
+    assert_eq!(phinary(7), "10000.0001");
+    assert_eq!(phinary(10), "10100.0101");
+}
+
+#[test]
+fn test_read_back() {
+    assert_eq!(integer("10.01").unwrap(), BigUint::from_u64(2));
+    assert_eq!(integer("1000.1001").unwrap(), BigUint::from_u64(5));
+    assert_eq!(integer("0").unwrap(), BigUint::zero());
+    assert!(integer("0.1").is_err());
+    assert!(integer("10").is_err());
+    assert!(integer("11").is_err());
+    assert!(from_phinary(&Number::new(vec![1, 2], Vec::new())).is_err());
+    assert!(integer("0.(01)").is_err());
+}
+
+#[test]
+fn test_phinary_roundtrip() {
+    for value in 0..=1500u64 {
+        let digits = to_phinary(&BigUint::from_u64(value));
+        let written: Vec<u32> = digits
+            .integer
+            .iter()
+            .chain(&digits.fractional)
+            .copied()
+            .collect();
+        assert!(!written.windows(2).any(|pair| pair == [1, 1]));
+        assert_eq!(from_phinary(&digits).unwrap(), BigUint::from_u64(value));
+    }
+}
+
+#[test]
+fn test_large_integer() {
+    let value = BigUint::from_u128(1 << 100);
+    assert_eq!(from_phinary(&to_phinary(&value)).unwrap(), value);
+}
@@ -0,0 +1,76 @@
+use base_converter::bigint::BigUint;
+use base_converter::roman::{MAX_ROMAN, from_roman, to_roman};
+
+fn roman(value: u64) -> String {
+    to_roman(&BigUint::from_u64(value)).unwrap()
+}
+
+fn strict(numeral: &str) -> Result<BigUint, String> {
+    from_roman(numeral, false)
+}
+
+fn lenient(numeral: &str) -> Result<BigUint, String> {
+    from_roman(numeral, true)
+}
+
+#[test]
+fn test_to_roman() {
+    assert_eq!(roman(0), "N");
+    assert_eq!(roman(1), "I");
+    assert_eq!(roman(4), "IV");
+    assert_eq!(roman(9), "IX");
+    assert_eq!(roman(14), "XIV");
+    assert_eq!(roman(1994), "MCMXCIV");
+    assert_eq!(roman(2026), "MMXXVI");
+    assert_eq!(roman(3999), "MMMCMXCIX");
+}
+
+#[test]
+fn test_vinculum() {
+    assert_eq!(roman(4000), "I\u{305}V\u{305}");
+    assert_eq!(roman(5001), "V\u{305}I");
+    assert_eq!(roman(1_000_000), "M\u{305}");
+    assert_eq!(strict("V\u{305}I").unwrap(), BigUint::from_u64(5001));
+    assert_eq!(
+        strict(&roman(MAX_ROMAN)).unwrap(),
+        BigUint::from_u64(MAX_ROMAN)
+    );
+    assert!(to_roman(&BigUint::from_u64(MAX_ROMAN + 1)).is_err());
+}
+
+#[test]
+fn test_strict_parsing() {
+    assert_eq!(strict("MCMXCIV").unwrap(), BigUint::from_u64(1994));
+    assert_eq!(strict("mmxxvi").unwrap(), BigUint::from_u64(2026));
+    assert_eq!(strict("N").unwrap(), BigUint::zero());
+    for numeral in [
+        "IIII", "VX", "IM", "IC", "XXXX", "VV", "IIV", "MMMM", "I\u{305}",
+    ] {
+        assert!(strict(numeral).is_err(), "{}", numeral);
+    }
+    assert!(strict("").is_err());
+    assert!(strict("ABC").is_err());
+}
+
+#[test]
+fn test_lenient_parsing() {
+    assert_eq!(lenient("IIII").unwrap(), BigUint::from_u64(4));
+    assert_eq!(lenient("VIIII").unwrap(), BigUint::from_u64(9));
+    assert_eq!(lenient("MMMM").unwrap(), BigUint::from_u64(4000));
+    assert_eq!(lenient("IM").unwrap(), BigUint::from_u64(999));
+    assert_eq!(lenient("I\u{305}").unwrap(), BigUint::from_u64(1000));
+    assert!(lenient("Q").is_err());
+}
+
+#[test]
+fn test_roundtrip() {
+    for value in (1..=5000).chain((3_990_000..=MAX_ROMAN).step_by(997)) {
+        let numeral = roman(value);
+        assert_eq!(
+            strict(&numeral).unwrap(),
+            BigUint::from_u64(value),
+            "{}",
+            numeral
+        );
+    }
+}
@@ -0,0 +1,78 @@
+use base_converter::bigint::BigUint;
+use base_converter::zeckendorf::{
+    fibonacci_decode, fibonacci_encode, from_zeckendorf, to_zeckendorf,
+};
+
+fn bits(text: &str) -> Vec<u32> {
+    text.chars().map(|c| c.to_digit(2).unwrap()).collect()
+}
+
+fn text(digits: &[u32]) -> String {
+    digits.iter().map(|d| d.to_string()).collect()
+}
+
+fn zeckendorf(value: u64) -> String {
+    text(&to_zeckendorf(&BigUint::from_u64(value)))
+}
+
+fn fibonacci(value: u64) -> String {
+    text(&fibonacci_encode(&BigUint::from_u64(value)).unwrap())
+}
+
+#[test]
+fn test_zeckendorf_representation() {
+    assert_eq!(zeckendorf(0), "0");
+    assert_eq!(zeckendorf(1), "1");
+    assert_eq!(zeckendorf(2), "10");
+    assert_eq!(zeckendorf(3), "100");
+    assert_eq!(zeckendorf(4), "101");
+    assert_eq!(zeckendorf(12), "10101");
+    assert_eq!(zeckendorf(100), "1000010100");
+    assert_eq!(
+        from_zeckendorf(&bits("1000010100")).unwrap(),
+        BigUint::from_u64(100)
+    );
+}
+
+#[test]
+fn test_zeckendorf_roundtrip() {
+    for value in 0..=2000u64 {
+        let digits = to_zeckendorf(&BigUint::from_u64(value));
+        assert!(!digits.windows(2).any(|pair| pair == [1, 1]));
+        assert_eq!(from_zeckendorf(&digits).unwrap(), BigUint::from_u64(value));
+    }
+}
+
+#[test]
+fn test_fibonacci_coding() {
+    assert_eq!(fibonacci(1), "11");
+    assert_eq!(fibonacci(2), "011");
+    assert_eq!(fibonacci(3), "0011");
+    assert_eq!(fibonacci(4), "1011");
+    assert_eq!(fibonacci(11), "001011");
+    assert_eq!(fibonacci(100), "00101000011");
+    assert!(fibonacci_encode(&BigUint::zero()).is_err());
+}
+
+#[test]
+fn test_fibonacci_stream() {
+    let stream = bits(&format!(
+        "{}{}{}",
+        fibonacci(4),
+        fibonacci(1),
+        fibonacci(100)
+    ));
+    assert_eq!(
+        fibonacci_decode(&stream).unwrap(),
+        vec![BigUint::from_u64(4), BigUint::one(), BigUint::from_u64(100)]
+    );
+    assert!(fibonacci_decode(&bits("1011101")).is_err());
+    assert!(fibonacci_decode(&[]).is_err());
+}
+
+#[test]
+fn test_zeckendorf_errors() {
+    assert!(from_zeckendorf(&bits("110")).is_err());
+    assert!(from_zeckendorf(&[1, 2]).is_err());
+    assert!(fibonacci_decode(&[2, 1, 1]).is_err());
+}